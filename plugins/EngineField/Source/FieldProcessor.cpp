@@ -7,10 +7,11 @@
 
 FieldProcessor::FieldProcessor()
     : juce::AudioProcessor (BusesProperties().withInput("Input", juce::AudioChannelSet::stereo(), true)
-                                               .withOutput("Output", juce::AudioChannelSet::stereo(), true))
+                                               .withOutput("Output", juce::AudioChannelSet::stereo(), true)
+                                               .withInput("Sidechain", juce::AudioChannelSet::stereo(), false))
 {
-    // Lock DSP shapes to Vowel pair by default (constant, set once)
-    zf_.setShapePair(emu::VOWEL_A, emu::VOWEL_B);
+    // Vowel pair until the SHAPE parameter says otherwise (checked per block)
+    zf_.setShapePair(emu::getPair(static_cast<size_t>(appliedShapePair_)));
     zf_.setIntensity(kIntensity);
     zf_.setDrive(kDrive);
     zf_.setSectionSaturation(kSat);
@@ -18,17 +19,41 @@ FieldProcessor::FieldProcessor()
     // Cache parameter pointers (avoid repeated APVTS lookups on audio thread)
     characterParam_ = apvts_.getRawParameterValue(enginefield::params::characterId);
     mixParam_ = apvts_.getRawParameterValue(enginefield::params::mixId);
+    intensityParam_ = apvts_.getRawParameterValue(enginefield::params::intensityId);
+    driveParam_ = apvts_.getRawParameterValue(enginefield::params::driveId);
+    saturationParam_ = apvts_.getRawParameterValue(enginefield::params::saturationId);
+    envDepthParam_ = apvts_.getRawParameterValue(enginefield::params::envDepthId);
+    keytrackParam_ = apvts_.getRawParameterValue(enginefield::params::keytrackId);
+    tuneParam_ = apvts_.getRawParameterValue(enginefield::params::tuneId);
     gainParam_ = apvts_.getRawParameterValue(enginefield::params::gainId);
+    inputGainParam_ = apvts_.getRawParameterValue(enginefield::params::inputGainId);
     bypassParam_ = apvts_.getRawParameterValue(enginefield::params::bypassId);
+    hostBypassParam_ = apvts_.getRawParameterValue(enginefield::params::hostBypassId);
     effectModeParam_ = apvts_.getRawParameterValue(enginefield::params::effectModeId);
+    outputInvertParam_ = apvts_.getRawParameterValue(enginefield::params::outputInvertId);
+    sidechainParam_ = apvts_.getRawParameterValue(enginefield::params::sidechainId);
+    outputLimitParam_ = apvts_.getRawParameterValue(enginefield::params::outputLimitId);
+    shapePairParam_ = apvts_.getRawParameterValue(enginefield::params::shapePairId);
     testToneParam_ = apvts_.getRawParameterValue(enginefield::params::testToneId);  // Patch 1
 
     // Validate cached pointers (fail fast if parameter IDs are wrong)
     jassert(characterParam_ != nullptr);
     jassert(mixParam_ != nullptr);
+    jassert(intensityParam_ != nullptr);
+    jassert(driveParam_ != nullptr);
+    jassert(saturationParam_ != nullptr);
+    jassert(envDepthParam_ != nullptr);
+    jassert(keytrackParam_ != nullptr);
+    jassert(tuneParam_ != nullptr);
     jassert(gainParam_ != nullptr);
+    jassert(inputGainParam_ != nullptr);
     jassert(bypassParam_ != nullptr);
+    jassert(hostBypassParam_ != nullptr);
     jassert(effectModeParam_ != nullptr);
+    jassert(outputInvertParam_ != nullptr);
+    jassert(sidechainParam_ != nullptr);
+    jassert(outputLimitParam_ != nullptr);
+    jassert(shapePairParam_ != nullptr);
     jassert(testToneParam_ != nullptr);
 
     // Initialize smoothing
@@ -42,7 +67,9 @@ void FieldProcessor::prepareToPlay(double sampleRate, int samplesPerBlock)
 {
     juce::dsp::ProcessSpec spec{ sampleRate, static_cast<juce::uint32>(samplesPerBlock), static_cast<juce::uint32>(getTotalNumOutputChannels()) };
     outGain_.prepare(spec);
-    outGain_.setRampDurationSeconds(0.02);  // 20ms smoothing for gain changes
+    appliedGainSmoothingMs_ = gainSmoothingMs_.load(std::memory_order_relaxed);
+    outGain_.setRampDurationSeconds(appliedGainSmoothingMs_ * 0.001);  // 20ms default smoothing for gain changes
+    outLimiter_.prepare(sampleRate);
 
     // Patch 3: Only resize dryBuffer_ if current size insufficient (avoid allocation if possible)
     if (dryBuffer_.getNumChannels() < getTotalNumOutputChannels() ||
@@ -52,12 +79,17 @@ void FieldProcessor::prepareToPlay(double sampleRate, int samplesPerBlock)
     }
 
     zf_.prepare(sampleRate, samplesPerBlock);
-    zf_.setSectionSaturation(kSat);
+    appliedSaturation_ = -1.0f;  // re-apply the SATURATION param on the next block
+    setLatencySamples(zf_.latencySamples());  // non-zero only in linear-phase (bounce) mode or with oversampling
 
     env_.prepare(sampleRate);
+    vowelSeq_.prepare(sampleRate);
     env_.setAttackMs(0.489f);
     env_.setReleaseMs(80.0f);
     env_.setDepth(0.75f);  // v1.0.1: Reduced from 0.945 for balanced modulation (±15% vs ±18.9%)
+    uiEnvelopeValue_.store(0.0f, std::memory_order_relaxed);
+    uiMaxPoleRadius_.store(0.0f, std::memory_order_relaxed);
+    lastNote_ = -1;  // a note from before the restart must not keep steering the morph
 
     // Initialize UI envelope follower
     const float sr = static_cast<float>(sampleRate);
@@ -70,8 +102,12 @@ void FieldProcessor::prepareToPlay(double sampleRate, int samplesPerBlock)
 
     // Reset smoothers with correct sample rate and initialize to current bypass state
     bypassSmooth_.reset(sampleRate, 0.01);
-    const bool currentBypass = bypassParam_->load() > 0.5f;
+    const bool currentBypass = bypassParam_->load() > 0.5f || hostBypassParam_->load() > 0.5f;
     bypassSmooth_.setCurrentAndTargetValue(currentBypass ? 0.0f : 1.0f);
+    envDepthSmooth_.reset(sampleRate, 0.02);
+    envDepthSmooth_.setCurrentAndTargetValue(envDepthParam_->load() * 0.01f);
+    saturationSmooth_.reset(sampleRate, 0.02);
+    saturationSmooth_.setCurrentAndTargetValue(saturationParam_->load() * 0.01f);
 
     uiWaveformFifo_.reset();
     uiWaveformRingBuffer_.assign(kWaveformDepth, 0.0f);
@@ -79,22 +115,46 @@ void FieldProcessor::prepareToPlay(double sampleRate, int samplesPerBlock)
 
 void FieldProcessor::releaseResources()
 {
+    lastNote_ = -1;
+}
+
+juce::AudioProcessorParameter* FieldProcessor::getBypassParameter() const
+{
+    return apvts_.getParameter(enginefield::params::hostBypassId);
 }
 
 bool FieldProcessor::isBusesLayoutSupported(const BusesLayout& layouts) const
 {
     const auto& in  = layouts.getChannelSet(true, 0);
     const auto& out = layouts.getChannelSet(false, 0);
-    return in == juce::AudioChannelSet::stereo() && out == juce::AudioChannelSet::stereo();
+    if (in != juce::AudioChannelSet::stereo() || out != juce::AudioChannelSet::stereo())
+        return false;
+
+    // Optional sidechain: disabled, mono or stereo (only its first channel feeds the envelope)
+    if (layouts.inputBuses.size() > 1)
+    {
+        const auto& sc = layouts.getChannelSet(true, 1);
+        return sc.isDisabled() || sc == juce::AudioChannelSet::mono() || sc == juce::AudioChannelSet::stereo();
+    }
+    return true;
 }
 
 void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiBuffer& midi)
 {
-    juce::ignoreUnused(midi);
     juce::ScopedNoDenormals noDenormals;
 
+    // MIDI: the last note-on in the block sets the keytracking target (block rate; note-off keeps it)
+    for (const auto metadata : midi)
+    {
+        const auto msg = metadata.getMessage();
+        if (msg.isNoteOn())
+            lastNote_ = msg.getNoteNumber();
+    }
+
     const auto numSamples = buffer.getNumSamples();
-    const auto numCh      = buffer.getNumChannels();
+    // The buffer also carries the sidechain channels (after the main ones) when that bus is enabled;
+    // everything below except the envelope source works on the main stereo pair only
+    const auto numCh      = juce::jmin(buffer.getNumChannels(), getMainBusNumOutputChannels());
 
     // Test tone (off by default) - Patch 1: use cached pointer, relaxed ordering (no cross-variable dependencies)
     const bool testTone = testToneParam_->load(std::memory_order_relaxed) > 0.5f;
@@ -125,23 +185,50 @@ void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiB
     const auto character = characterParam_->load();
     const auto mixPct    = mixParam_->load();
     const auto outDb     = gainParam_->load();
-    const bool bypass    = bypassParam_->load() > 0.5f;
+    const bool bypass    = bypassParam_->load() > 0.5f
+                        || hostBypassParam_->load() > 0.5f;  // plugin OR host soft bypass
     const bool effectOn  = effectModeParam_->load() > 0.5f;
 
     // Targets (mix smoothing happens inside ZPlaneFilter)
     const float mixTarget = juce::jlimit(0.0f, 1.0f, mixPct * 0.01f);
     bypassSmooth_.setTargetValue(bypass ? 0.0f : 1.0f); // 1 = active, 0 = bypassed
 
-    // Envelope follower on left channel (authentic)
-    float envValue = 0.0f;
+    // Envelope follower on left channel (authentic), or on the sidechain when enabled and connected
+    const auto sidechainBus = getBusBuffer(buffer, true, 1);
+    const float* envSource = selectEnvelopeSource(buffer.getReadPointer(0),
+                                                  sidechainBus.getNumChannels() > 0 ? sidechainBus.getReadPointer(0) : nullptr,
+                                                  sidechainParam_->load(std::memory_order_relaxed) > 0.5f);
+    const float envValue = env_.processBlock(envSource, numSamples);
+
+    // Publish the envelope for the editor meter (lock-free; not a host parameter, so not saved in state)
+    uiEnvelopeValue_.store(juce::jlimit(0.0f, 1.0f, envValue), std::memory_order_relaxed);
+
+    // Vowel sequence (when active) replaces CHARACTER as the base morph
+    applyPendingVowelSequence();
+    float baseMorph = character * 0.01f;
+    if (vowelSeq_.isActive())
     {
-        const float* L = buffer.getReadPointer(0);
-        for (int i = 0; i < numSamples; ++i) envValue = env_.process(L[i]);
+        double bpm = 120.0;
+        if (auto* ph = getPlayHead())
+            if (auto pos = ph->getPosition())
+                if (auto hostBpm = pos->getBpm())
+                    bpm = *hostBpm;
+        baseMorph = vowelSeq_.advance(numSamples, bpm);
     }
 
-    // Modulate morph by envelope depth (20% scale as per spec example)
-    const float baseMorph = character * 0.01f;
-    const float modulatedMorph = juce::jlimit(0.0f, 1.0f, baseMorph + envValue * 0.2f);
+    // Keytracking blends the base morph towards the played note (no effect until a note arrives)
+    if (lastNote_ >= 0)
+    {
+        const float keytrack = keytrackParam_->load(std::memory_order_relaxed) * 0.01f;
+        baseMorph += keytrack * (noteToMorph(lastNote_) - baseMorph);
+    }
+
+    // Modulate morph by the envelope (ENV DEPTH, -100..+100 %, smoothed; negative morphs downward)
+    // Per-channel routing: left drives the shared morph, right is applied as an offset from it
+    envDepthSmooth_.setTargetValue(envDepthParam_->load(std::memory_order_relaxed) * 0.01f);
+    const float envDepth = envDepthSmooth_.skip(numSamples);
+    const float modulatedMorph  = envelopeMorph(baseMorph, envValue, envDepth, envDepthL_.load(std::memory_order_relaxed));
+    const float modulatedMorphR = envelopeMorph(baseMorph, envValue, envDepth, envDepthR_.load(std::memory_order_relaxed));
 
     // EFFECT mode: SOLO THE WET SIGNAL (100% wet) so you can hear what the Engine is doing
     // Off = normal (respects MIX knob target)
@@ -149,11 +236,42 @@ void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiB
     const float effectiveMix = effectOn ? 1.0f : mixTarget;
 
     // Update filter parameters (skip constant setters - already set in constructor)
+    // Input trim lives in the filter so the dry tap (raw vs trimmed) is selectable there
+    zf_.setInputGain(juce::Decibels::decibelsToGain(inputGainParam_->load(std::memory_order_relaxed)));
+    zf_.setIntensity(intensityParam_->load(std::memory_order_relaxed) * 0.01f);  // perceptual knob curve lives in the range; smoothed in the filter
+    zf_.setDrive(driveParam_->load(std::memory_order_relaxed) * 0.01f);          // smoothed (10 ms) in the filter
+
+    // Section saturation: smoothed at block rate, only touch the 12 sections when the value actually moves
+    saturationSmooth_.setTargetValue(saturationParam_->load(std::memory_order_relaxed) * 0.01f);
+    const float saturation = saturationSmooth_.skip(numSamples);
+    if (saturation != appliedSaturation_)
+    {
+        zf_.setSectionSaturation(saturation);
+        appliedSaturation_ = saturation;
+    }
+    // Shape pair: reload the poles only when the choice actually changes
+    if (const int pair = static_cast<int>(shapePairParam_->load(std::memory_order_relaxed)); pair != appliedShapePair_)
+    {
+        zf_.setShapePair(emu::getPair(static_cast<size_t>(pair)));
+        appliedShapePair_ = pair;
+    }
+    // Tune marks the coefficients dirty, so only forward actual changes
+    if (const float tune = tuneParam_->load(std::memory_order_relaxed); tune != appliedTune_)
+    {
+        zf_.setFrequencyShift(tune);
+        appliedTune_ = tune;
+    }
     zf_.setMorph(modulatedMorph);
+    zf_.setRightMorphOffset(modulatedMorphR - modulatedMorph);
         zf_.setMix(effectiveMix);
 
     // Update coefficients once per block (expensive)
     zf_.updateCoeffsBlock(numSamples);
+    // Publish the max pole radius (% of the unit circle) for the editor meter (lock-free, not saved in state)
+    uiMaxPoleRadius_.store(100.0f * zf_.maxRadius(), std::memory_order_relaxed);
+
+    tailSeconds_.store(zf_.tailSamples(static_cast<float>(getSampleRate())) / juce::jmax(1.0, getSampleRate()),
+                       std::memory_order_relaxed);
 
     // Copy pole data to UI atomics (lock-free, cheap)
     const auto& poles = zf_.getLastPoles();
@@ -166,7 +284,10 @@ void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiB
     // Process (cheap)
     float* L = buffer.getWritePointer(0);
     float* R = buffer.getWritePointer(1);
-    zf_.process(L, R, numSamples);
+    if (effectOn && zf_.isFullyWet())
+        zf_.processWetOnly(L, R, numSamples);  // EFFECT mode once the mix ramp has landed at 100%
+    else
+        zf_.process(L, R, numSamples);
 
     // Compute wet/dry block peaks (pre-bypass mix) for delta visualization
     // Cache channel pointers once before loops
@@ -194,11 +315,34 @@ void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiB
         waveformIndex_.store((idx + 1) % NUM_WAVEFORM_BARS, std::memory_order_relaxed);
     }
 
-    // Bypass crossfade (per-sample smoothing to avoid zipper noise)
     // Cache write pointers once (already have read pointers from above)
     float* wetLW = buffer.getWritePointer(0);
     float* wetRW = numCh > 1 ? buffer.getWritePointer(1) : wetLW;
 
+    // Output gain (juce::dsp::Gain smooths internally via setRampDurationSeconds)
+    if (const float ms = gainSmoothingMs_.load(std::memory_order_relaxed); ms != appliedGainSmoothingMs_)
+    {
+        appliedGainSmoothingMs_ = ms;
+        outGain_.setRampDurationSeconds(ms * 0.001);
+    }
+    outGain_.setGainLinear(juce::Decibels::decibelsToGain(outDb));
+    juce::dsp::AudioBlock<float> blk (buffer);
+    auto mainBlk = blk.getSubsetChannelBlock(0, static_cast<size_t>(numCh));
+    outGain_.process(juce::dsp::ProcessContextReplacing<float>(mainBlk));
+
+    // Optional soft limiter on the final output (state reset while off so enabling starts clean)
+    if (outputLimitParam_->load(std::memory_order_relaxed) > 0.5f && numCh > 1)
+        outLimiter_.processStereo(wetLW, wetRW, numSamples);
+    else
+        outLimiter_.reset();
+
+    // Polarity invert: its own sign stage, so toggling it never runs through the output gain ramp
+    if (outputInvertParam_->load(std::memory_order_relaxed) > 0.5f)
+        for (int ch = 0; ch < numCh; ++ch)
+            juce::FloatVectorOperations::negate(buffer.getWritePointer(ch), buffer.getReadPointer(ch), numSamples);
+
+    // Bypass crossfade (per-sample smoothing to avoid zipper noise). Last, so a fully bypassed
+    // block is the untouched dry signal (no output gain, invert or limiter)
     for (int i = 0; i < numSamples; ++i)
     {
         const float bypassAmt = bypassSmooth_.getNextValue();
@@ -207,12 +351,6 @@ void FieldProcessor::processBlock (juce::AudioBuffer<float>& buffer, juce::MidiB
             wetRW[i] = wetRW[i] * bypassAmt + dryR[i] * (1.0f - bypassAmt);
     }
 
-    // Output gain (juce::dsp::Gain smooths internally via setRampDurationSeconds)
-    const float gainLinear = juce::Decibels::decibelsToGain(outDb);
-    outGain_.setGainLinear(gainLinear);
-    juce::dsp::AudioBlock<float> blk (buffer);
-    outGain_.process(juce::dsp::ProcessContextReplacing<float>(blk));
-
     // --- UI: compute overall output level AFTER all DSP (for meters) ---
     // Use already-cached pointers (wetLW/wetRW now contain final output)
     {
@@ -257,6 +395,98 @@ void FieldProcessor::setStateInformation (const void* data, int sizeInBytes)
             apvts_.replaceState(juce::ValueTree::fromXml(*xml));
 }
 
+namespace
+{
+    // Share code layout: [version u8][payload size u16][payload][FNV-1a u32 of payload], as unpadded
+    // URL-safe base64 ('-' and '_' instead of '+' and '/')
+    // v1 payload: shape pair name (UTF-8, null-terminated), then morph/intensity/drive/saturation as 0..1 floats.
+    // Readers only accept their own version: a newer code may carry settings this build cannot apply
+    constexpr juce::uint8 kShareCodeVersion = 1;
+
+    juce::uint32 fnv1a(const void* data, size_t size) noexcept
+    {
+        juce::uint32 h = 2166136261u;
+        for (size_t i = 0; i < size; ++i)
+            h = (h ^ static_cast<const juce::uint8*>(data)[i]) * 16777619u;
+        return h;
+    }
+}
+
+juce::String FieldProcessor::toShareCode() const
+{
+    juce::MemoryOutputStream payload;
+    payload.writeString(emu::getPair(static_cast<size_t>(shapePairParam_->load())).name);
+    payload.writeFloat(characterParam_->load() * 0.01f);
+    payload.writeFloat(intensityParam_->load() * 0.01f);
+    payload.writeFloat(driveParam_->load() * 0.01f);
+    payload.writeFloat(saturationParam_->load() * 0.01f);
+
+    juce::MemoryOutputStream out;
+    out.writeByte(static_cast<char>(kShareCodeVersion));
+    out.writeShort(static_cast<short>(payload.getDataSize()));
+    out.write(payload.getData(), payload.getDataSize());
+    out.writeInt(static_cast<int>(fnv1a(payload.getData(), payload.getDataSize())));
+
+    return juce::Base64::toBase64(out.getData(), out.getDataSize())
+               .replaceCharacter('+', '-').replaceCharacter('/', '_').trimCharactersAtEnd("=");
+}
+
+bool FieldProcessor::fromShareCode(const juce::String& code)
+{
+    auto base64 = code.trim().replaceCharacter('-', '+').replaceCharacter('_', '/');
+    if (base64.length() % 4 == 1)
+        return false;
+    while (base64.length() % 4 != 0)
+        base64 << "=";
+
+    juce::MemoryOutputStream bin;
+    if (!juce::Base64::convertFromBase64(bin, base64) || bin.getDataSize() < 3)
+        return false;
+
+    juce::MemoryInputStream in(bin.getData(), bin.getDataSize(), false);
+    const auto version = static_cast<juce::uint8>(in.readByte());
+    const auto payloadSize = static_cast<size_t>(static_cast<juce::uint16>(in.readShort()));
+    if (version != kShareCodeVersion || static_cast<size_t>(in.getNumBytesRemaining()) != payloadSize + 4)
+        return false;
+
+    const auto* payloadData = static_cast<const char*>(bin.getData()) + 3;
+    juce::MemoryInputStream checksumIn(payloadData + payloadSize, 4, false);
+    if (static_cast<juce::uint32>(checksumIn.readInt()) != fnv1a(payloadData, payloadSize))
+        return false;
+
+    juce::MemoryInputStream payload(payloadData, payloadSize, false);
+    const auto pairName = payload.readString();
+    const auto pairIt = std::find_if(emu::SHAPE_PAIRS.begin(), emu::SHAPE_PAIRS.end(),
+                                     [&pairName](const emu::ShapePair& p) { return pairName == p.name; });
+    if (pairIt == emu::SHAPE_PAIRS.end() || payload.getNumBytesRemaining() != 16)
+        return false;
+
+    float values[4];
+    for (auto& v : values)
+    {
+        v = payload.readFloat();
+        if (!std::isfinite(v) || v < 0.0f || v > 1.0f)
+            return false;
+    }
+
+    const char* ids[] = { enginefield::params::characterId, enginefield::params::intensityId,
+                          enginefield::params::driveId, enginefield::params::saturationId };
+    const auto write = [this](const char* id, float value)
+    {
+        if (auto* param = apvts_.getParameter(id))
+        {
+            param->beginChangeGesture();
+            param->setValueNotifyingHost(param->convertTo0to1(value));
+            param->endChangeGesture();
+        }
+    };
+    write(enginefield::params::shapePairId, static_cast<float>(std::distance(emu::SHAPE_PAIRS.begin(), pairIt)));
+    for (int i = 0; i < 4; ++i)
+        write(ids[i], values[i] * 100.0f);
+
+    return true;
+}
+
 // RT-safe reader for the editor (no allocations)
 int FieldProcessor::getWaveformSamples(float* destBuffer, int maxSamples) noexcept
 {
@@ -279,3 +509,40 @@ int FieldProcessor::getWaveformSamples(float* destBuffer, int maxSamples) noexce
     uiWaveformFifo_.finishedRead(size1 + size2);
     return (size1 + size2);
 }
+
+// Message thread: queue a vowel sequence for the audio thread (no allocations, no locks)
+bool FieldProcessor::setVowelSequence(const float* positions, int count, float rate, bool tempoSync) noexcept
+{
+    int start1 = 0, size1 = 0, start2 = 0, size2 = 0;
+    seqFifo_.prepareToWrite(1, start1, size1, start2, size2);
+    if (size1 == 0)
+        return false;
+
+    auto& slot = pendingSeqs_[static_cast<size_t>(start1)];
+    slot.count = juce::jlimit(0, emu::VowelSequencer::MaxSteps, count);
+    for (int i = 0; i < slot.count; ++i)
+        slot.positions[static_cast<size_t>(i)] = positions[i];
+    slot.rate = rate;
+    slot.tempoSync = tempoSync;
+    seqFifo_.finishedWrite(1);
+    return true;
+}
+
+// Audio thread: apply queued sequences in order (the newest one wins)
+void FieldProcessor::applyPendingVowelSequence() noexcept
+{
+    const int ready = seqFifo_.getNumReady();
+    if (ready == 0)
+        return;
+
+    int start1 = 0, size1 = 0, start2 = 0, size2 = 0;
+    seqFifo_.prepareToRead(ready, start1, size1, start2, size2);
+    const auto apply = [this](const PendingSequence& seq)
+    {
+        vowelSeq_.setSequence(seq.positions.data(), seq.count, seq.rate);
+        vowelSeq_.setTempoSync(seq.tempoSync);
+    };
+    for (int i = 0; i < size1; ++i) apply(pendingSeqs_[static_cast<size_t>(start1 + i)]);
+    for (int i = 0; i < size2; ++i) apply(pendingSeqs_[static_cast<size_t>(start2 + i)]);
+    seqFifo_.finishedRead(size1 + size2);
+}
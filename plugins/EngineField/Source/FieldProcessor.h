@@ -7,6 +7,8 @@
 #include "dsp/ZPlaneFilter.h"
 #include "dsp/EMUAuthenticTables.h"
 #include "dsp/EnvelopeFollower.h"
+#include "dsp/OutputLimiter.h"
+#include "dsp/VowelSequencer.h"
 #include "ui/VisualisationConfig.h"
 
 #include <atomic>
@@ -20,9 +22,9 @@ public:
 
     //==============================================================================
     const juce::String getName() const override { return "EngineField"; }
-    bool acceptsMidi() const override { return false; }
+    bool acceptsMidi() const override { return true; }
     bool producesMidi() const override { return false; }
-    double getTailLengthSeconds() const override { return 0.0; }
+    double getTailLengthSeconds() const override { return tailSeconds_.load(std::memory_order_relaxed); }
 
     //==============================================================================
     void prepareToPlay(double sampleRate, int samplesPerBlockExpected) override;
@@ -45,8 +47,58 @@ public:
     void getStateInformation (juce::MemoryBlock& destData) override;
     void setStateInformation (const void* data, int sizeInBytes) override;
 
+    // Compact, versioned settings code for sharing (URL-safe base64): shape pair name, CHARACTER, intensity,
+    // drive, saturation. fromShareCode selects the pair by name and returns false (and changes nothing) for
+    // corrupted or malformed codes, codes from another format version, and pair names this build does not
+    // know. Each parameter is written inside its own change gesture so hosts record it as one edit
+    juce::String toShareCode() const;
+    bool fromShareCode(const juce::String& code);
+
+    // Host-level soft bypass (VST3 kIsBypass) mapped to a dedicated parameter
+    juce::AudioProcessorParameter* getBypassParameter() const override;
+
     juce::AudioProcessorValueTreeState& getAPVTS() noexcept { return apvts_; }
 
+    // Output gain ramp time (default 20 ms); applied on the audio thread at the next block
+    void setGainSmoothingMs(float ms) noexcept { gainSmoothingMs_.store(std::max(0.0f, ms), std::memory_order_relaxed); }
+
+    // Envelope -> CHARACTER routing per channel: bipolar depth (-1..1, negative = opposite direction)
+    void setEnvelopeRouting(float depthL, float depthR) noexcept
+    {
+        envDepthL_.store(juce::jlimit(-1.0f, 1.0f, depthL), std::memory_order_relaxed);
+        envDepthR_.store(juce::jlimit(-1.0f, 1.0f, depthR), std::memory_order_relaxed);
+    }
+
+    // Talk-box morph sequence (count = 0 returns control to CHARACTER).
+    // Rate is steps/second, or steps/beat when tempo-synced. Safe to call during playback: the sequence is
+    // queued lock-free and picked up at the start of the next block. Returns false if the queue is full
+    bool setVowelSequence(const float* positions, int count, float rate, bool tempoSync = false) noexcept;
+
+    // MIDI note -> morph position for keytracking: C2 (36) maps to 0, C7 (96) to 1, clamped outside
+    static constexpr int kKeytrackLowNote  = 36;
+    static constexpr int kKeytrackHighNote = 96;
+    static constexpr float noteToMorph(int note) noexcept
+    {
+        const float t = static_cast<float>(note - kKeytrackLowNote) / static_cast<float>(kKeytrackHighNote - kKeytrackLowNote);
+        return t < 0.0f ? 0.0f : (t > 1.0f ? 1.0f : t);
+    }
+
+    // Envelope-modulated CHARACTER: base + envelope * ENV DEPTH (-1..1) * channel routing, clamped to 0..1.
+    // Negative depth (or routing) morphs downward as the input gets louder
+    static constexpr float envelopeMorph(float baseMorph, float envValue, float depth, float routing = 1.0f) noexcept
+    {
+        const float m = baseMorph + envValue * depth * routing;
+        return m < 0.0f ? 0.0f : (m > 1.0f ? 1.0f : m);
+    }
+
+    // Envelope source: the sidechain's first channel when enabled and the host actually supplies the bus,
+    // otherwise the main left channel (authentic). A connected-but-silent sidechain is honoured (the
+    // envelope decays), only a missing/disabled bus (nullptr) falls back
+    static const float* selectEnvelopeSource(const float* mainLeft, const float* sidechain, bool sidechainEnabled) noexcept
+    {
+        return (sidechainEnabled && sidechain != nullptr) ? sidechain : mainLeft;
+    }
+
     // UI pole data (lock-free: audio thread writes, UI reads)
     const std::array<std::atomic<float>, 12>& getUIPoles() const noexcept { return uiPoles_; }
 
@@ -75,6 +127,15 @@ public:
     int getWaveformSamples(float* destBuffer, int maxSamples) noexcept;
     float getCurrentLevel() const noexcept { return uiCurrentLevel_.load(std::memory_order_relaxed); }
 
+    // CHARACTER modulation envelope (0..1), stored once per block (lock-free meter source)
+    float getEnvelopeValue() const noexcept { return uiEnvelopeValue_.load(std::memory_order_relaxed); }
+
+    // Largest pole radius in percent of the unit circle (how close to instability), stored once per block
+    float getMaxPoleRadius() const noexcept { return uiMaxPoleRadius_.load(std::memory_order_relaxed); }
+
+    // Filter outputs zeroed as non-finite since load (diagnostics: rising = unstable coefficients)
+    juce::uint64 getNanEvents() const noexcept { return zf_.nanEvents(); }
+
 private:
     // Parameters
     juce::AudioProcessorValueTreeState apvts_{
@@ -84,23 +145,60 @@ private:
     // DSP
     emu::ZPlaneFilter zf_;
     emu::EnvelopeFollower env_;
+    emu::VowelSequencer vowelSeq_;
+
+    // Lock-free SPSC hand-over of vowel sequences (producer: message thread, consumer: audio thread)
+    struct PendingSequence
+    {
+        std::array<float, emu::VowelSequencer::MaxSteps> positions{};
+        int count = 0;
+        float rate = 0.0f;
+        bool tempoSync = false;
+    };
+    static constexpr int kSequenceQueueDepth = 4;
+    juce::AbstractFifo seqFifo_{ kSequenceQueueDepth };
+    std::array<PendingSequence, kSequenceQueueDepth> pendingSeqs_{};
+    void applyPendingVowelSequence() noexcept;
+    std::atomic<float> envDepthL_ { 1.0f }, envDepthR_ { 1.0f };
     juce::dsp::Gain<float> outGain_;
+    emu::OutputLimiter outLimiter_;
+    std::atomic<float> gainSmoothingMs_ { 20.0f };
+    float appliedGainSmoothingMs_ { 20.0f };
+    std::atomic<double> tailSeconds_ { 0.0 }; // refreshed per block from the current poles
 
-    // Fixed authentic values (locked)
+    // Fixed authentic values (locked); intensity, drive and saturation are host-controlled, these are their initial values
     static constexpr float kIntensity = emu::AUTHENTIC_INTENSITY;
     static constexpr float kDrive     = emu::AUTHENTIC_DRIVE;
     static constexpr float kSat       = emu::AUTHENTIC_SATURATION;
 
     // Smoothing (gain smoothing handled by juce::dsp::Gain internally)
     juce::LinearSmoothedValue<float> bypassSmooth_;
+    juce::LinearSmoothedValue<float> envDepthSmooth_; // block-rate, 20 ms
+    juce::LinearSmoothedValue<float> saturationSmooth_; // block-rate, 20 ms
     juce::UndoManager undo_;
 
     // Cached parameter pointers (avoid repeated atomic lookups in processBlock)
     std::atomic<float>* characterParam_ = nullptr;
     std::atomic<float>* mixParam_ = nullptr;
+    std::atomic<float>* intensityParam_ = nullptr;
+    std::atomic<float>* driveParam_ = nullptr;
+    std::atomic<float>* saturationParam_ = nullptr;
+    float appliedSaturation_ = -1.0f; // last value pushed into the sections (-1 = force re-apply)
+    std::atomic<float>* envDepthParam_ = nullptr;
+    std::atomic<float>* keytrackParam_ = nullptr;
+    std::atomic<float>* tuneParam_ = nullptr;
+    float appliedTune_ = 0.0f;
+    int lastNote_ = -1; // last MIDI note-on (-1 = none yet; keytracking stays inactive)
     std::atomic<float>* gainParam_ = nullptr;
+    std::atomic<float>* inputGainParam_ = nullptr;
     std::atomic<float>* bypassParam_ = nullptr;
+    std::atomic<float>* hostBypassParam_ = nullptr;
     std::atomic<float>* effectModeParam_ = nullptr;
+    std::atomic<float>* outputInvertParam_ = nullptr;
+    std::atomic<float>* sidechainParam_ = nullptr;
+    std::atomic<float>* outputLimitParam_ = nullptr;
+    std::atomic<float>* shapePairParam_ = nullptr;
+    int appliedShapePair_ = 0; // SHAPE_PAIRS index loaded into the filter
     std::atomic<float>* testToneParam_ = nullptr;  // Patch 1: avoid APVTS tree traversal
 
     // Dry buffer (pre-allocated)
@@ -134,6 +232,8 @@ private:
     float uiEnvelopeAttackCoef_ = 0.0f;
     float uiEnvelopeReleaseCoef_ = 0.0f;
     std::atomic<float> uiCurrentLevel_{ 0.0f };
+    std::atomic<float> uiEnvelopeValue_{ 0.0f };
+    std::atomic<float> uiMaxPoleRadius_{ 0.0f };
 
     JUCE_DECLARE_NON_COPYABLE_WITH_LEAK_DETECTOR(FieldProcessor)
 };
@@ -1,7 +1,9 @@
 
 #pragma once
 #include <array>
+#include <cmath>
 #include <cstddef>
+#include <cstring>
 
 // AUTHENTIC EMU Z-Plane shapes extracted from real hardware
 // Each shape = 6 complex pole pairs stored as [r, theta] (12 floats)
@@ -9,6 +11,11 @@ namespace emu
 {
     using Shape = std::array<float, 12>;
 
+    // Pole range for runtime-loaded shapes, shared by tryLoadShape and the text parser: radius in [0, 1)
+    // (a pole on the unit circle never decays), finite angle. NaN fails both
+    inline bool isValidPoleRadius(float r) noexcept { return r >= 0.0f && r < 1.0f; }
+    inline bool isValidPoleAngle(float theta) noexcept { return std::isfinite(theta); }
+
     // Vowel Pair (default)
     inline constexpr Shape VOWEL_A = {
         0.95f,  0.01047197551529928f,
@@ -84,4 +91,31 @@ namespace emu
         0.98f,  0.10471975511f,
         0.97f,  0.13962634016f
     };
+
+    // Named shape pairs (index order is stable: used for selection and state)
+    // intensityCurve: exponent on the intensity knob for this pair (1 = linear, > 1 = gentler low end)
+    struct ShapePair { const char* name; const Shape* a; const Shape* b; const char* description; float intensityCurve; };
+
+    inline constexpr std::array<ShapePair, 4> SHAPE_PAIRS = {{
+        { "Vowel", &VOWEL_A, &VOWEL_B, "Open vowel formants, talking-filter sweep", 1.0f },
+        { "Bell",  &BELL_A,  &BELL_B,  "Bright metallic resonances",                1.6f },
+        { "Low",   &LOW_A,   &LOW_B,   "Punchy bass emphasis",                      1.0f },
+        { "Sub",   &SUB_A,   &SUB_B,   "Ultra-low rumble",                          0.75f }
+    }};
+
+    // Tooltip text for a built-in pair name (nullptr if unknown)
+    inline const char* describePair(const char* name) noexcept
+    {
+        if (name == nullptr) return nullptr;
+        for (const auto& p : SHAPE_PAIRS)
+            if (std::strcmp(p.name, name) == 0)
+                return p.description;
+        return nullptr;
+    }
+
+    // Built-in pair by index (out-of-range falls back to Vowel)
+    inline const ShapePair& getPair(size_t index) noexcept
+    {
+        return SHAPE_PAIRS[index < SHAPE_PAIRS.size() ? index : 0];
+    }
 }
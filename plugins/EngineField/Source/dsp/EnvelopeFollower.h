@@ -4,6 +4,14 @@
 
 namespace emu
 {
+    // One-pole detector pre-filter ahead of the rectifier (frequency-selective dynamics)
+    enum class DetectorFilter { None, LowPass, HighPass };
+
+    // Level detection: full-wave peak (default, original calibration) or mean-square with sqrt output.
+    // Rms smooths x^2 with the same attack/release, so it reads true RMS when the two are comparable
+    // (a fast attack rides the peaks of x^2 and reads close to peak level)
+    enum class DetectionMode { Peak, Rms };
+
     // EnvelopeFollower — RT-safe (no per-sample exp), parameter-change-rate recompute
     // Patch 2: Moved std::exp() from process() to updateCoefficients() (~95% CPU reduction)
     struct EnvelopeFollower
@@ -11,7 +19,7 @@ namespace emu
         void prepare(double sampleRate) noexcept
         {
             sr = sampleRate;
-            state = 0.0f;
+            reset();
             updateCoefficients();  // Precompute on sample rate change
         }
 
@@ -27,26 +35,79 @@ namespace emu
             updateCoefficients();  // Recompute when release changes
         }
 
+        // Hold (0 = off): after the input drops below the envelope, keep it flat this long before releasing
+        void setHoldMs(float ms) noexcept
+        {
+            holdMs = std::max(0.0f, ms);
+            updateCoefficients();  // Recompute when hold changes
+        }
+
         void setDepth(float d) noexcept { depth = d; }
 
-        void reset() noexcept { state = 0.0f; }
+        void setDetectorFilter(DetectorFilter mode, float freqHz) noexcept
+        {
+            detectorMode = mode;
+            detectorHz = freqHz;
+            updateCoefficients();  // Recompute when detector cutoff changes
+        }
+
+        void setDetectionMode(DetectionMode m) noexcept { detectionMode = m; state = 0.0f; }
+
+        void reset() noexcept { state = 0.0f; detectorLp_ = 0.0f; holdCounter_ = 0; }
 
         float process(float input) noexcept
         {
-            const float rect = std::abs(input);
+            if (detectorMode != DetectorFilter::None)
+            {
+                detectorLp_ += detectorCoef_ * (input - detectorLp_);
+                input = (detectorMode == DetectorFilter::LowPass) ? detectorLp_ : input - detectorLp_;
+            }
+
+            // Rms: the smoother runs on the squared input, the output is its sqrt
+            const float rect = (detectionMode == DetectionMode::Rms) ? input * input : std::abs(input);
             // Patch 2: O(1) branch instead of per-sample exp() (~150 cycles → ~1 cycle)
-            const float alpha = (rect > state) ? attackCoef_ : releaseCoef_;
-            state += alpha * (rect - state);
-            return std::clamp(state * depth, 0.0f, 1.0f);
+            if (rect > state)
+            {
+                state += attackCoef_ * (rect - state);
+                holdCounter_ = holdSamples_;
+            }
+            else if (holdCounter_ > 0)
+            {
+                --holdCounter_; // hold: envelope stays flat
+            }
+            else
+            {
+                state += releaseCoef_ * (rect - state);
+            }
+            return output();
+        }
+
+        // Advance across a block, returning the final envelope value (same as looping process())
+        float processBlock(const float* input, int numSamples) noexcept
+        {
+            float env = output();
+            for (int i = 0; i < numSamples; ++i)
+                env = process(input[i]);
+            return env;
         }
 
         double sr { 48000.0 };
         float  state { 0.0f };
         float  attackMs { 0.489f };
         float  releaseMs { 80.0f };
+        float  holdMs { 0.0f };
         float  depth { 0.945f };
+        DetectorFilter detectorMode { DetectorFilter::None };
+        float  detectorHz { 1000.0f };
+        DetectionMode detectionMode { DetectionMode::Peak };
 
     private:
+        float output() const noexcept
+        {
+            const float level = (detectionMode == DetectionMode::Rms) ? std::sqrt(state) : state;
+            return std::clamp(level * depth, 0.0f, 1.0f);
+        }
+
         void updateCoefficients() noexcept
         {
             const float attackSec  = attackMs * 0.001f;
@@ -55,9 +116,15 @@ namespace emu
             // Precompute expensive exp() terms outside the hot loop
             attackCoef_  = 1.0f - std::exp(-1.0f / std::max(1e-6f, attackSec  * srF));
             releaseCoef_ = 1.0f - std::exp(-1.0f / std::max(1e-6f, releaseSec * srF));
+            detectorCoef_ = 1.0f - std::exp(-2.0f * 3.14159265f * std::max(1.0f, detectorHz) / srF);
+            holdSamples_ = static_cast<int>(std::lround(holdMs * 0.001f * srF));
         }
 
         float attackCoef_  { 0.0f };
         float releaseCoef_ { 0.0f };
+        float detectorCoef_ { 0.0f };
+        float detectorLp_ { 0.0f };
+        int   holdSamples_ { 0 };
+        int   holdCounter_ { 0 };
     };
 }
@@ -0,0 +1,52 @@
+#pragma once
+#include "ZPlaneFilter.h" // softKneeClip
+
+#include <cmath>
+#include <algorithm>
+
+namespace emu
+{
+    // Output safety limiter: stereo-linked peak gain reduction (instant attack, one-pole release, no
+    // lookahead) keeps the level at or below 0 dBFS, then a tanh soft knee from -6 dBFS catches the
+    // attack transients. Identity below the knee while no reduction is active. RT-safe, no allocation
+    struct OutputLimiter
+    {
+        static constexpr float Knee = 0.5f; // -6 dBFS
+
+        void prepare(double sampleRate) noexcept
+        {
+            sr = sampleRate;
+            setReleaseMs(releaseMs);
+            reset();
+        }
+
+        void reset() noexcept { env = 1.0f; }
+
+        void setReleaseMs(float ms) noexcept
+        {
+            releaseMs = std::max(1.0f, ms);
+            releaseCoef = std::exp(-1.0f / (releaseMs * 0.001f * static_cast<float>(sr)));
+        }
+
+        // env tracks max(peak, 1): the gain is 1 / env, so nothing happens until the input exceeds 0 dBFS
+        inline void processStereo(float* left, float* right, int numSamples) noexcept
+        {
+            for (int i = 0; i < numSamples; ++i)
+            {
+                const float peak = std::max(1.0f, std::max(std::abs(left[i]), std::abs(right[i])));
+                env = peak > env ? peak : peak + releaseCoef * (env - peak);
+                const float g = 1.0f / env;
+                left[i]  = softKneeClip(left[i]  * g, Knee);
+                right[i] = softKneeClip(right[i] * g, Knee);
+            }
+        }
+
+        // Current gain reduction in dB (<= 0), for metering
+        float gainReductionDb() const noexcept { return -20.0f * std::log10(env); }
+
+        double sr{48000.0};
+        float releaseMs{100.0f};
+        float releaseCoef{0.0f};
+        float env{1.0f};
+    };
+}
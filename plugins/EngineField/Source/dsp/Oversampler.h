@@ -0,0 +1,125 @@
+#pragma once
+#include <array>
+#include <cmath>
+#include <algorithm>
+
+namespace emu
+{
+    // 2x polyphase half-band stage: 31-tap Blackman-windowed sinc, only the odd side taps are
+    // non-zero so each direction costs K multiply-adds per base-rate sample. Up + down round trip
+    // delays the signal by exactly 2K - 1 base-rate samples. RT-safe, fixed size, no allocation
+    struct HalfbandStage
+    {
+        static constexpr int K = 8; // side taps per half (filter length 4K - 1)
+
+        HalfbandStage() noexcept
+        {
+            constexpr double pi = 3.14159265358979323846;
+            constexpr int len = 4 * K - 1;
+            double sum = 0.0;
+            for (int j = 0; j < K; ++j)
+            {
+                const int n = (2 * K - 1) + (2 * j + 1); // tap index right of the centre
+                const double x = 0.5 * (2 * j + 1);
+                const double w = 0.42 - 0.5 * std::cos(2.0 * pi * n / (len - 1)) + 0.08 * std::cos(4.0 * pi * n / (len - 1));
+                g[static_cast<size_t>(j)] = 0.5 * std::sin(pi * x) / (pi * x) * w;
+                sum += g[static_cast<size_t>(j)];
+            }
+            // Full filter DC gain = 0.5 (centre) + 2 * sum(side taps) = 1
+            for (auto& v : g) v *= 0.25 / sum;
+        }
+
+        void reset() noexcept { hx.fill(0.0f); he.fill(0.0f); ho.fill(0.0f); }
+
+        // One input sample -> two output samples (unity passband gain)
+        inline void up(float x, float& out0, float& out1) noexcept
+        {
+            std::copy(hx.begin() + 1, hx.end(), hx.begin());
+            hx[2 * K - 1] = x;
+
+            // hx[2K-1] = x[n]; centre phase is x[n-K], side phase straddles it
+            float acc = 0.0f;
+            for (int j = 0; j < K; ++j)
+                acc += static_cast<float>(g[static_cast<size_t>(j)]) * (hx[static_cast<size_t>(K - 1 - j)] + hx[static_cast<size_t>(K + j)]);
+
+            out0 = hx[static_cast<size_t>(K - 1)];
+            out1 = 2.0f * acc;
+        }
+
+        // Two input samples -> one output sample
+        inline float down(float in0, float in1) noexcept
+        {
+            std::copy(he.begin() + 1, he.end(), he.begin());
+            std::copy(ho.begin() + 1, ho.end(), ho.begin());
+            he[K - 1] = in0;
+            ho[2 * K - 1] = in1;
+
+            float acc = 0.5f * he[0];
+            for (int j = 0; j < K; ++j)
+                acc += static_cast<float>(g[static_cast<size_t>(j)]) * (ho[static_cast<size_t>(K - 1 - j)] + ho[static_cast<size_t>(K + j)]);
+            return acc;
+        }
+
+        static constexpr int latencySamples() noexcept { return 2 * K - 1; }
+
+        std::array<double, K> g{};
+        std::array<float, 2 * K> hx{}; // base-rate input history
+        std::array<float, K> he{};     // even-phase history (down)
+        std::array<float, 2 * K> ho{}; // odd-phase history (down)
+    };
+
+    // 1x / 2x / 4x streaming oversampler built from cascaded half-band stages. Per base-rate sample:
+    // up() fills factor() samples, the caller processes them in place, down() returns one sample.
+    // 4x adds half a base-rate sample of delay so the reported latency stays an integer
+    struct Oversampler
+    {
+        static constexpr int MaxFactor = 4;
+
+        void setFactor(int f) noexcept
+        {
+            factor_ = (f >= 4) ? 4 : (f >= 2 ? 2 : 1);
+            reset();
+        }
+
+        int factor() const noexcept { return factor_; }
+
+        void reset() noexcept { stage1.reset(); stage2.reset(); align = 0.0f; }
+
+        // Round-trip delay in base-rate samples
+        int latencySamples() const noexcept
+        {
+            if (factor_ == 2) return HalfbandStage::latencySamples();
+            if (factor_ == 4) return HalfbandStage::latencySamples() + (HalfbandStage::latencySamples() + 1) / 2;
+            return 0;
+        }
+
+        inline void up(float x, float* out) noexcept
+        {
+            if (factor_ == 1) { out[0] = x; return; }
+
+            float a, b;
+            stage1.up(x, a, b);
+            if (factor_ == 2) { out[0] = a; out[1] = b; return; }
+
+            stage2.up(a, out[0], out[1]);
+            stage2.up(b, out[2], out[3]);
+        }
+
+        inline float down(const float* in) noexcept
+        {
+            if (factor_ == 1) return in[0];
+            if (factor_ == 2) return stage1.down(in[0], in[1]);
+
+            // One 2x-rate sample of delay between the stages (see latencySamples)
+            const float a = stage2.down(in[0], in[1]);
+            const float b = stage2.down(in[2], in[3]);
+            const float prev = align;
+            align = b;
+            return stage1.down(prev, a);
+        }
+
+        int factor_{1};
+        HalfbandStage stage1, stage2;
+        float align{0.0f};
+    };
+}
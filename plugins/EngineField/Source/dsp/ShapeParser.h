@@ -0,0 +1,118 @@
+#pragma once
+#include <cctype>
+#include <cmath>
+#include <cstdlib>
+#include <string>
+#include <string_view>
+
+#include "EMUAuthenticTables.h"
+
+namespace emu
+{
+    // Text shape loading for user `.shape` files: 12 floats (r0 theta0 r1 theta1 ...), separated by
+    // whitespace and/or commas. A pair file holds shape A and shape B separated by a blank line.
+    // Allocates: setup/message thread only
+    enum class ShapeParseError { None, BadNumber, TooFewValues, TooManyValues, RadiusOutOfRange, NonFiniteTheta, MissingSecondShape };
+
+    struct ShapeParseStatus
+    {
+        ShapeParseError error{ShapeParseError::None};
+        int valueIndex{-1}; // offending value (0..11), -1 if not value-specific
+        int shapeIndex{0};  // 0 = A, 1 = B (parsePair)
+
+        bool ok() const noexcept { return error == ShapeParseError::None; }
+
+        const char* message() const noexcept
+        {
+            switch (error)
+            {
+                case ShapeParseError::BadNumber:          return "value is not a number";
+                case ShapeParseError::TooFewValues:       return "expected 12 values (6 r/theta pairs), got fewer";
+                case ShapeParseError::TooManyValues:      return "expected 12 values (6 r/theta pairs), got more";
+                case ShapeParseError::RadiusOutOfRange:   return "pole radius must be in [0, 1)";
+                case ShapeParseError::NonFiniteTheta:     return "pole angle must be finite";
+                case ShapeParseError::MissingSecondShape: return "expected two shapes separated by a blank line";
+                case ShapeParseError::None:               break;
+            }
+            return "ok";
+        }
+    };
+
+    // `out` is only written when the whole shape is valid
+    inline ShapeParseStatus parseShape(std::string_view text, Shape& out)
+    {
+        Shape values{};
+        int count = 0;
+        size_t pos = 0;
+        const auto isSep = [](char c) { return c == ',' || std::isspace(static_cast<unsigned char>(c)) != 0; };
+
+        while (pos < text.size())
+        {
+            while (pos < text.size() && isSep(text[pos])) ++pos;
+            if (pos >= text.size()) break;
+
+            size_t end = pos;
+            while (end < text.size() && !isSep(text[end])) ++end;
+
+            if (count >= static_cast<int>(values.size()))
+                return { ShapeParseError::TooManyValues, count };
+
+            const std::string token(text.substr(pos, end - pos));
+            char* parsedEnd = nullptr;
+            const float v = std::strtof(token.c_str(), &parsedEnd);
+            if (parsedEnd != token.c_str() + token.size())
+                return { ShapeParseError::BadNumber, count };
+
+            const bool isRadius = (count % 2) == 0;
+            if (isRadius && !isValidPoleRadius(v))
+                return { ShapeParseError::RadiusOutOfRange, count };
+            if (!isRadius && !isValidPoleAngle(v))
+                return { ShapeParseError::NonFiniteTheta, count };
+
+            values[static_cast<size_t>(count++)] = v;
+            pos = end;
+        }
+
+        if (count < static_cast<int>(values.size()))
+            return { ShapeParseError::TooFewValues, count };
+
+        out = values;
+        return {};
+    }
+
+    // Shape A, blank line, shape B. Outputs are only written when both shapes are valid
+    inline ShapeParseStatus parsePair(std::string_view text, Shape& a, Shape& b)
+    {
+        // Split at the first blank line that follows some content
+        size_t lineStart = 0, split = std::string_view::npos;
+        bool seenContent = false;
+        while (lineStart <= text.size())
+        {
+            size_t lineEnd = text.find('\n', lineStart);
+            if (lineEnd == std::string_view::npos) lineEnd = text.size();
+
+            bool blank = true;
+            for (size_t i = lineStart; i < lineEnd; ++i)
+                if (!std::isspace(static_cast<unsigned char>(text[i]))) { blank = false; break; }
+
+            if (!blank) seenContent = true;
+            else if (seenContent && lineEnd < text.size()) { split = lineEnd; break; }
+
+            lineStart = lineEnd + 1;
+        }
+
+        if (split == std::string_view::npos)
+            return { ShapeParseError::MissingSecondShape, -1 };
+
+        Shape sa{}, sb{};
+        auto status = parseShape(text.substr(0, split), sa);
+        if (!status.ok()) return status;
+
+        status = parseShape(text.substr(split), sb);
+        if (!status.ok()) { status.shapeIndex = 1; return status; }
+
+        a = sa;
+        b = sb;
+        return {};
+    }
+}
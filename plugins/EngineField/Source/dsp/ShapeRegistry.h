@@ -0,0 +1,37 @@
+#pragma once
+#include <string>
+#include <unordered_map>
+#include <utility>
+
+#include "EMUAuthenticTables.h"
+
+namespace emu
+{
+    // Named shape pairs extendable at runtime (e.g. pairs parsed from .shape files), seeded with the
+    // built-ins. Not RT-safe (allocates, no locking): populate at setup time on the message thread and
+    // copy the chosen shapes into the filter. getPair() keeps working against the built-in set only.
+    struct ShapeRegistry
+    {
+        using Entry = std::pair<Shape, Shape>;
+
+        ShapeRegistry()
+        {
+            for (const auto& p : SHAPE_PAIRS)
+                pairs.emplace(p.name, Entry{ *p.a, *p.b });
+        }
+
+        // Adds or replaces a pair
+        void add(const std::string& name, const Shape& a, const Shape& b) { pairs[name] = Entry{ a, b }; }
+
+        // nullptr if no pair is registered under `name`
+        const Entry* get(const std::string& name) const
+        {
+            const auto it = pairs.find(name);
+            return it != pairs.end() ? &it->second : nullptr;
+        }
+
+        size_t size() const noexcept { return pairs.size(); }
+
+        std::unordered_map<std::string, Entry> pairs;
+    };
+}
@@ -0,0 +1,85 @@
+#pragma once
+#include <algorithm>
+#include <cmath>
+#include <cstdint>
+#include <vector>
+
+namespace emu::testsignals
+{
+    // Deterministic generators for offline checks / QA tools (same seed -> same samples on every platform)
+
+    inline constexpr double TwoPi = 6.283185307179586;
+
+    // xorshift32 -> uniform float in [-1, 1)
+    struct Rng
+    {
+        explicit Rng(uint32_t seed) noexcept : state(seed != 0 ? seed : 0x9E3779B9u) {}
+
+        float next() noexcept
+        {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            return static_cast<float>(state >> 8) * (2.0f / 16777216.0f) - 1.0f;
+        }
+
+        uint32_t state;
+    };
+
+    // Uniform white noise, RMS ~0.577
+    inline std::vector<float> whiteNoise(uint32_t seed, size_t n)
+    {
+        Rng rng(seed);
+        std::vector<float> out(n);
+        for (auto& x : out) x = rng.next();
+        return out;
+    }
+
+    // Pink (-3 dB/oct) noise via Paul Kellet's refined filter, scaled to roughly unit peak
+    inline std::vector<float> pinkNoise(uint32_t seed, size_t n)
+    {
+        Rng rng(seed);
+        std::vector<float> out(n);
+        float b0 = 0, b1 = 0, b2 = 0, b3 = 0, b4 = 0, b5 = 0, b6 = 0;
+        for (auto& x : out)
+        {
+            const float w = rng.next();
+            b0 = 0.99886f * b0 + w * 0.0555179f;
+            b1 = 0.99332f * b1 + w * 0.0750759f;
+            b2 = 0.96900f * b2 + w * 0.1538520f;
+            b3 = 0.86650f * b3 + w * 0.3104856f;
+            b4 = 0.55000f * b4 + w * 0.5329522f;
+            b5 = -0.7616f * b5 - w * 0.0168980f;
+            x = (b0 + b1 + b2 + b3 + b4 + b5 + b6 + w * 0.5362f) * 0.11f;
+            b6 = w * 0.115926f;
+        }
+        return out;
+    }
+
+    // Exponential (log-frequency) sweep from startHz to endHz over n samples, unit amplitude
+    inline std::vector<float> logChirp(float startHz, float endHz, size_t n, float sampleRate)
+    {
+        std::vector<float> out(n);
+        const double f0 = std::max(1.0e-3f, startHz), f1 = std::max(1.0e-3f, endHz);
+        const double dur = static_cast<double>(n) / sampleRate;
+        const double k = std::log(f1 / f0);
+        for (size_t i = 0; i < n; ++i)
+        {
+            const double t = static_cast<double>(i) / sampleRate;
+            const double phase = (std::abs(k) < 1.0e-9) ? TwoPi * f0 * t
+                                                        : TwoPi * f0 * dur / k * (std::exp(k * t / dur) - 1.0);
+            out[i] = static_cast<float>(std::sin(phase));
+        }
+        return out;
+    }
+
+    // Unit-amplitude sine
+    inline std::vector<float> sine(float freqHz, size_t n, float sampleRate)
+    {
+        std::vector<float> out(n);
+        const double w = TwoPi * freqHz / sampleRate;
+        for (size_t i = 0; i < n; ++i)
+            out[i] = static_cast<float>(std::sin(w * static_cast<double>(i)));
+        return out;
+    }
+}
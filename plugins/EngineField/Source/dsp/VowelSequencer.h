@@ -0,0 +1,62 @@
+#pragma once
+#include <array>
+#include <cmath>
+#include <algorithm>
+
+namespace emu
+{
+    // VowelSequencer — talk-box style morph stepping through a list of positions
+    // Free-running (steps per second) or tempo-synced (steps per beat), smoothstep between steps
+    struct VowelSequencer
+    {
+        static constexpr int MaxSteps = 16;
+
+        void prepare(double sampleRate) noexcept
+        {
+            sr = sampleRate;
+            phase = 0.0;
+        }
+
+        // count = 0 disables the sequencer (CHARACTER drives the morph again)
+        void setSequence(const float* positions, int count, float rate) noexcept
+        {
+            numSteps = std::clamp(count, 0, MaxSteps);
+            for (int i = 0; i < numSteps; ++i)
+                steps[static_cast<size_t>(i)] = std::clamp(positions[i], 0.0f, 1.0f);
+            stepRate = std::max(0.0f, rate);
+            phase = 0.0;
+        }
+
+        // When synced, rate is interpreted as steps per beat at the host tempo
+        void setTempoSync(bool on) noexcept { tempoSync = on; }
+
+        bool isActive() const noexcept { return numSteps > 0; }
+
+        // Advance by a block and return the morph position at the block start
+        float advance(int numSamples, double bpm) noexcept
+        {
+            const float morph = current();
+            const double stepsPerSecond = tempoSync ? stepRate * std::max(1.0, bpm) / 60.0 : stepRate;
+            phase = std::fmod(phase + stepsPerSecond * numSamples / sr, static_cast<double>(std::max(1, numSteps)));
+            return morph;
+        }
+
+        float current() const noexcept
+        {
+            if (numSteps == 0) return 0.5f;
+            const int k = static_cast<int>(phase) % numSteps;
+            const float frac = static_cast<float>(phase - std::floor(phase));
+            const float t = frac * frac * (3.0f - 2.0f * frac); // smoothstep glide
+            const float a = steps[static_cast<size_t>(k)];
+            const float b = steps[static_cast<size_t>((k + 1) % numSteps)];
+            return a + t * (b - a);
+        }
+
+        double sr { 48000.0 };
+        double phase { 0.0 };   // in steps
+        float  stepRate { 2.0f };
+        bool   tempoSync { false };
+        int    numSteps { 0 };
+        std::array<float, MaxSteps> steps{};
+    };
+}
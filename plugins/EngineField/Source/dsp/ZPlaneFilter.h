@@ -1,8 +1,16 @@
 #pragma once
 #include <array>
+#include <atomic>
 #include <cmath>
+#include <complex>
+#include <cstdint>
 #include <algorithm>
+#include <type_traits>
+#include <utility>
+#include <vector>
 #include <juce_dsp/juce_dsp.h>
+#include "EMUAuthenticTables.h"
+#include "Oversampler.h"
 
 namespace emu
 {
@@ -19,6 +27,34 @@ namespace emu
 
     struct PolePair { float r; float theta; };
 
+    // Normalized biquad coefficients (a0 = 1), as loaded into a BiquadSection
+    struct BiquadCoeffs { float b0, b1, b2, a1, a2; };
+
+    // Perceptual intensity curve: knob position [0,1] -> intensity [0,1]
+    // Concave exponential so more knob travel lands in the high-resonance region
+    inline float intensityPerceptual(float knob) noexcept
+    {
+        constexpr float k = 3.0f;
+        const float x = std::clamp(knob, 0.0f, 1.0f);
+        return 1.0f - std::expm1(k * (1.0f - x)) / std::expm1(k);
+    }
+
+    // Inverse of intensityPerceptual: intensity [0,1] -> knob position [0,1]
+    inline float intensityPerceptualInverse(float intensity) noexcept
+    {
+        constexpr float k = 3.0f;
+        const float y = std::clamp(intensity, 0.0f, 1.0f);
+        return 1.0f - std::log1p((1.0f - y) * std::expm1(k)) / k;
+    }
+
+    // Denormal mitigation inside BiquadSection::process
+    // Flush: zero the state once it has decayed below the threshold (portable default, no FTZ/DAZ needed)
+    // DcOffset: inject a tiny constant into the input so the recursion never decays into denormals
+    enum class DenormalStrategy { Flush, DcOffset };
+
+    inline constexpr float DENORMAL_THRESHOLD = 1.0e-15f;
+    inline constexpr float DENORMAL_DC        = 1.0e-18f;
+
     struct BiquadSection
     {
         void setCoeffs(float nb0, float nb1, float nb2, float na1, float na2) noexcept
@@ -26,29 +62,93 @@ namespace emu
             b0 = nb0; b1 = nb1; b2 = nb2; a1 = na1; a2 = na2;
         }
 
+        // Linear coefficient ramp toward new targets over `steps` samples (advanced by advanceRamp)
+        void rampCoeffsTo(float nb0, float nb1, float nb2, float na1, float na2, int steps) noexcept
+        {
+            if (steps <= 1) { setCoeffs(nb0, nb1, nb2, na1, na2); rampLeft = 0; return; }
+            const float inv = 1.0f / static_cast<float>(steps);
+            tb0 = nb0; tb1 = nb1; tb2 = nb2; ta1 = na1; ta2 = na2;
+            db0 = (nb0 - b0) * inv; db1 = (nb1 - b1) * inv; db2 = (nb2 - b2) * inv;
+            da1 = (na1 - a1) * inv; da2 = (na2 - a2) * inv;
+            rampLeft = steps;
+        }
+
+        inline void advanceRamp() noexcept
+        {
+            if (rampLeft <= 0) return;
+            if (--rampLeft == 0) { setCoeffs(tb0, tb1, tb2, ta1, ta2); return; }
+            b0 += db0; b1 += db1; b2 += db2; a1 += da1; a2 += da2;
+        }
+
         void setSaturation(float amt) noexcept { sat = std::clamp(amt, 0.0f, 1.0f); }
 
         void reset() noexcept { z1 = z2 = 0.0f; }
 
+        // Direct state access (diagnostics / regression tests only)
+        void setState(float nz1, float nz2) noexcept { z1 = nz1; z2 = nz2; }
+        std::pair<float, float> state() const noexcept { return { z1, z2 }; }
+
+        // Linear gain at DC (z = 1) and Nyquist (z = -1), ignoring saturation
+        float dcGain() const noexcept      { return std::abs(b0 + b1 + b2) / std::max(1.0e-9f, std::abs(1.0f + a1 + a2)); }
+        float nyquistGain() const noexcept { return std::abs(b0 - b1 + b2) / std::max(1.0e-9f, std::abs(1.0f - a1 + a2)); }
+
+        // Linear magnitude at normalized angular frequency w (rad/sample), ignoring saturation
+        float magnitude(float w) const noexcept
+        {
+            if (passthrough) return 1.0f;
+            using cf = std::complex<float>;
+            const cf e1 = std::polar(1.0f, -w), e2 = e1 * e1;
+            const cf num = b0 + b1 * e1 + b2 * e2;
+            const cf den = 1.0f + a1 * e1 + a2 * e2;
+            return std::abs(num) / std::max(1.0e-9f, std::abs(den));
+        }
+
+        // Phase (rad, wrapped to ±pi) at normalized angular frequency w, ignoring saturation
+        float phase(float w) const noexcept
+        {
+            if (passthrough) return 0.0f;
+            using cf = std::complex<float>;
+            const cf e1 = std::polar(1.0f, -w), e2 = e1 * e1;
+            return std::arg((b0 + b1 * e1 + b2 * e2) / (1.0f + a1 * e1 + a2 * e2));
+        }
+
         inline float process(float x) noexcept
         {
+            if (passthrough) return x;
+
+            if (denormal == DenormalStrategy::DcOffset)
+                x += DENORMAL_DC;
+
             // Direct Form II Transposed
             float y = b0 * x + z1;
             z1 = b1 * x - a1 * y + z2;
             z2 = b2 * x - a2 * y;
 
+            // Flush both states together: zeroing only one of them skews the decaying oscillation and
+            // can leave the pair cycling just above the threshold instead of settling to exact zero
+            if (denormal == DenormalStrategy::Flush && std::abs(z1) + std::abs(z2) < DENORMAL_THRESHOLD)
+                z1 = z2 = 0.0f;
+
             if (sat > 0.0f) {
                 const float g = 1.0f + sat * 4.0f;
                 y = std::tanh(y * g);
             }
 
-            if (!std::isfinite(y)) y = 0.0f;
+            if (!std::isfinite(y)) { y = 0.0f; ++nonFiniteCount; }
             return y;
         }
 
         float z1{0}, z2{0};
         float b0{1}, b1{0}, b2{0}, a1{0}, a2{0};
         float sat{AUTHENTIC_SATURATION};
+        bool passthrough{false}; // identity section (skips filtering and saturation)
+        uint32_t nonFiniteCount{0}; // outputs zeroed as non-finite since the last drain (audio thread only)
+        DenormalStrategy denormal{DenormalStrategy::Flush};
+
+        // Coefficient ramp state (rampCoeffsTo / advanceRamp)
+        float tb0{1}, tb1{0}, tb2{0}, ta1{0}, ta2{0};
+        float db0{0}, db1{0}, db2{0}, da1{0}, da2{0};
+        int rampLeft{0};
     };
 
     template <size_t N>
@@ -60,9 +160,282 @@ namespace emu
             for (auto& s: sections) x = s.process(x);
             return x;
         }
+
+        inline void advanceRamp() noexcept { for (auto& s: sections) s.advanceRamp(); }
+
+        float magnitude(float w) const noexcept
+        {
+            float g = 1.0f;
+            for (const auto& s: sections) g *= s.magnitude(w);
+            return g;
+        }
+
+        // Linear / dB cascade gain at freqHz (H(z) of each section evaluated at z = e^{jw}, w = 2pi f / fs)
+        float magnitudeAt(float freqHz, float sampleRate) const noexcept
+        {
+            return magnitude(6.283185307f * freqHz / std::max(1.0f, sampleRate));
+        }
+        float magnitudeDbAt(float freqHz, float sampleRate) const noexcept
+        {
+            return 20.0f * std::log10(std::max(1.0e-9f, magnitudeAt(freqHz, sampleRate)));
+        }
+
+        // Unwrapped cascade phase (rad) at w, tracked from DC in small steps so 2pi jumps are removed
+        float unwrappedPhase(float w) const noexcept
+        {
+            constexpr float twoPi = 6.283185307f;
+            const int steps = std::max(16, static_cast<int>(std::abs(w) / 0.002f)); // << resonance bandwidth at r = 0.995
+            float acc = 0.0f, prev = 0.0f;
+            for (int k = 1; k <= steps; ++k)
+            {
+                float ph = 0.0f;
+                for (const auto& s: sections) ph += s.phase(w * static_cast<float>(k) / static_cast<float>(steps));
+                acc += std::remainder(ph - prev, twoPi);
+                prev = ph;
+            }
+            return acc;
+        }
+
+        // Group delay (samples) at w: -dphi/dw by central difference (phase is odd, so w = 0 is fine)
+        float groupDelay(float w) const noexcept
+        {
+            constexpr float h = 1.0e-3f;
+            float up = 0.0f, dn = 0.0f;
+            for (const auto& s: sections) { up += s.phase(w + h); dn += s.phase(w - h); }
+            return -std::remainder(up - dn, 6.283185307f) / (2.0f * h);
+        }
+
+        // Phase delay (samples) at w: -phi(w)/w, falling back to the group delay as w -> 0
+        float phaseDelay(float w) const noexcept
+        {
+            if (std::abs(w) < 1.0e-4f) return groupDelay(0.0f);
+            return -unwrappedPhase(w) / w;
+        }
+
+        // Closed-form cascade gain at DC / Nyquist (product of section gains)
+        float dcGain() const noexcept
+        {
+            float g = 1.0f;
+            for (const auto& s: sections) g *= s.dcGain();
+            return g;
+        }
+        float nyquistGain() const noexcept
+        {
+            float g = 1.0f;
+            for (const auto& s: sections) g *= s.nyquistGain();
+            return g;
+        }
         std::array<BiquadSection, N> sections;
     };
 
+    // First-order all-pass: H(z) = (-g + z^-1) / (1 - g z^-1), unity magnitude, phase set by g
+    struct FirstOrderAllpass
+    {
+        void reset() noexcept { x1 = y1 = 0.0f; }
+
+        inline float process(float x) noexcept
+        {
+            const float y = -g * x + x1 + g * y1;
+            x1 = x;
+            y1 = y;
+            return y;
+        }
+
+        float g{0.0f};
+        float x1{0.0f}, y1{0.0f};
+    };
+
+    // First-order high-pass for rumble/DC removal: y = a (y1 + x - x1), a = exp(-2pi fc / fs)
+    struct OnePoleHighpass
+    {
+        void setCutoff(float hz, double fs) noexcept
+        {
+            a = static_cast<float>(std::exp(-6.283185307179586 * std::max(0.0f, hz) / std::max(1.0, fs)));
+        }
+
+        void reset() noexcept { x1 = y1 = 0.0f; }
+
+        inline float process(float x) noexcept
+        {
+            const float y = a * (y1 + x - x1);
+            x1 = x;
+            y1 = y;
+            return y;
+        }
+
+        float a{1.0f};
+        float x1{0.0f}, y1{0.0f};
+    };
+
+    // DC blocker: y = x - x1 + R y1 (pole just inside z = 1)
+    struct DcBlocker
+    {
+        void reset() noexcept { x1 = y1 = 0.0f; }
+
+        inline float process(float x) noexcept
+        {
+            const float y = x - x1 + R * y1;
+            x1 = x;
+            y1 = y;
+            return y;
+        }
+
+        float R{0.995f};
+        float x1{0.0f}, y1{0.0f};
+    };
+
+    inline uint64_t splitMix64(uint64_t x) noexcept
+    {
+        x += 0x9E3779B97F4A7C15ull;
+        x = (x ^ (x >> 30)) * 0xBF58476D1CE4E5B9ull;
+        x = (x ^ (x >> 27)) * 0x94D049BB133111EBull;
+        return x ^ (x >> 31);
+    }
+
+    // Distinct seed per call (process-wide counter, scrambled), so filter instances never share a stream
+    inline uint64_t nextInstanceSeed() noexcept
+    {
+        static std::atomic<uint64_t> counter{0};
+        return splitMix64(counter.fetch_add(1, std::memory_order_relaxed));
+    }
+
+    // Relaxed atomic event counter that stays copyable, so the filter can still be snapshotted by value
+    struct EventCounter
+    {
+        EventCounter() noexcept = default;
+        EventCounter(const EventCounter& o) noexcept : value(o.load()) {}
+        EventCounter& operator=(const EventCounter& o) noexcept { value.store(o.load(), std::memory_order_relaxed); return *this; }
+
+        void add(uint64_t n) noexcept { value.fetch_add(n, std::memory_order_relaxed); }
+        uint64_t load() const noexcept { return value.load(std::memory_order_relaxed); }
+
+        std::atomic<uint64_t> value{0};
+    };
+
+    // xorshift64* white noise in [-1, 1); the shared source for stochastic stages (dither, noise, ...)
+    struct NoiseSource
+    {
+        explicit NoiseSource(uint64_t s = 0) noexcept { seed(s); }
+
+        void seed(uint64_t s) noexcept { state = splitMix64(s) | 1ull; }
+
+        inline float next() noexcept
+        {
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            const uint64_t r = state * 0x2545F4914F6CDD1Dull;
+            return static_cast<float>(r >> 40) * (2.0f / 16777216.0f) - 1.0f;
+        }
+
+        uint64_t state{ 1 };
+    };
+
+    // 4th-order Linkwitz-Riley band split (two Butterworth biquads per band). Low + high sums to an
+    // all-pass, so recombining the bands keeps the magnitude flat
+    struct CrossoverLR4
+    {
+        void setCutoff(float hz, double fs) noexcept
+        {
+            const double fc = std::clamp(static_cast<double>(hz), 20.0, 0.45 * fs);
+            const double k = std::tan(3.141592653589793 * fc / fs), kk = k * k;
+            const double q = 0.7071067811865476;
+            const double norm = 1.0 / (1.0 + k / q + kk);
+            const auto a1 = static_cast<float>(2.0 * (kk - 1.0) * norm);
+            const auto a2 = static_cast<float>((1.0 - k / q + kk) * norm);
+            const auto lp = static_cast<float>(kk * norm), hp = static_cast<float>(norm);
+            for (auto& sct : low.sections)  { sct.setCoeffs(lp, 2.0f * lp, lp, a1, a2); sct.setSaturation(0.0f); }
+            for (auto& sct : high.sections) { sct.setCoeffs(hp, -2.0f * hp, hp, a1, a2); sct.setSaturation(0.0f); }
+        }
+
+        void reset() noexcept { low.reset(); high.reset(); }
+
+        inline void split(float x, float& lo, float& hi) noexcept
+        {
+            lo = low.process(x);
+            hi = high.process(x);
+        }
+
+        BiquadCascade<2> low, high;
+    };
+
+    // Equal-power dry/wet gains; endpointSmoothing > 0 blends toward linear near mix = 0 / 1
+    // where the sqrt curve has infinite slope (the middle of the range stays equal-power)
+    inline void mixGains(float mix, float endpointSmoothing, float& wetG, float& dryG) noexcept
+    {
+        wetG = std::sqrt(mix);
+        dryG = std::sqrt(1.0f - mix);
+        if (endpointSmoothing > 0.0f)
+        {
+            const float e = 2.0f * mix - 1.0f;
+            const float w = endpointSmoothing * e * e;
+            wetG += w * (mix - wetG);
+            dryG += w * ((1.0f - mix) - dryG);
+        }
+    }
+
+    // Soft-knee limiter: identity below `knee`, tanh-shaped above, output bounded to ±1
+    inline float softKneeClip(float x, float knee) noexcept
+    {
+        const float a = std::abs(x);
+        if (a <= knee) return x;
+        const float span = 1.0f - knee;
+        return std::copysign(knee + span * std::tanh((a - knee) / span), x);
+    }
+
+    // Tuned feedback comb with a linearly interpolated fractional delay (buffer sized in prepare)
+    struct FeedbackComb
+    {
+        void prepare(int maxDelaySamples)
+        {
+            buf.assign(static_cast<size_t>(std::max(4, maxDelaySamples + 2)), 0.0f);
+            pos = 0;
+        }
+
+        void reset() noexcept { std::fill(buf.begin(), buf.end(), 0.0f); pos = 0; }
+
+        // Peaks at multiples of fs/delaySamples; output scaled by (1 - fb) to keep peaks near unity
+        inline float process(float x, float delaySamples, float fb) noexcept
+        {
+            const int size = static_cast<int>(buf.size());
+            if (size == 0) return x;
+
+            float readPos = static_cast<float>(pos) - delaySamples;
+            while (readPos < 0.0f) readPos += static_cast<float>(size);
+            const int i0 = static_cast<int>(readPos) % size;
+            const int i1 = (i0 + 1) % size;
+            const float frac = readPos - std::floor(readPos);
+            const float delayed = buf[static_cast<size_t>(i0)] + frac * (buf[static_cast<size_t>(i1)] - buf[static_cast<size_t>(i0)]);
+
+            const float y = x + fb * delayed;
+            buf[static_cast<size_t>(pos)] = y;
+            pos = (pos + 1) % size;
+            return y * (1.0f - fb);
+        }
+
+        std::vector<float> buf;
+        int pos{0};
+    };
+
+    // Fixed-capacity integer delay line (RT-safe, no allocation)
+    template <size_t Capacity>
+    struct IntegerDelay
+    {
+        void reset() noexcept { buf.fill(0.0f); pos = 0; }
+
+        inline float process(float x, int delay) noexcept
+        {
+            buf[pos] = x;
+            const size_t d = static_cast<size_t>(std::clamp(delay, 0, static_cast<int>(Capacity) - 1));
+            const float y = buf[(pos + Capacity - d) % Capacity];
+            pos = (pos + 1) % Capacity;
+            return y;
+        }
+
+        std::array<float, Capacity> buf{};
+        size_t pos{0};
+    };
+
     inline float wrapAngle(float a) noexcept
     {
         const float pi = juce::MathConstants<float>::pi;
@@ -71,14 +444,21 @@ namespace emu
         return a;
     }
 
+    // How the two shapes' radii combine while morphing
+    // Blend: geodesic/linear interpolation, Min: widest bandwidth wins, Max: sharpest wins
+    // (Min/Max pull toward the extreme with a triangle weight peaking at the midpoint, so endpoints stay exact)
+    enum class RadiusRule { Blend, Min, Max };
+
     // Interpolate pole pair at 48k reference (before bilinear remap)
     // Intensity boost is applied AFTER interpolation and remap in updateCoeffsBlock
-    inline PolePair interpolatePole(const PolePair& A, const PolePair& B, float t) noexcept
+    inline PolePair interpolatePole(const PolePair& A, const PolePair& B, float t,
+                                    bool geodesic = GEODESIC_RADIUS,
+                                    RadiusRule rule = RadiusRule::Blend) noexcept
     {
         PolePair result;
 
         // Radius: geodesic (log-space) or linear interpolation
-        if constexpr (GEODESIC_RADIUS) {
+        if (geodesic) {
             const float lnA = std::log(std::max(1.0e-9f, A.r));
             const float lnB = std::log(std::max(1.0e-9f, B.r));
             result.r = std::exp((1.0f - t) * lnA + t * lnB);
@@ -86,6 +466,12 @@ namespace emu
             result.r = A.r + t * (B.r - A.r); // linear
         }
 
+        if (rule != RadiusRule::Blend) {
+            const float target = (rule == RadiusRule::Max) ? std::max(A.r, B.r) : std::min(A.r, B.r);
+            const float w = std::clamp(1.0f - std::abs(2.0f * t - 1.0f), 0.0f, 1.0f);
+            result.r += w * (target - result.r);
+        }
+
         // Angle: shortest path
         float d = wrapAngle(B.theta - A.theta);
         result.theta = A.theta + t * d;
@@ -136,21 +522,73 @@ namespace emu
         return result;
     }
 
-    inline void poleToBiquad(const PolePair& p, float& a1, float& a2, float& b0, float& b1, float& b2) noexcept
+    // zeroFactor places the zeros at zeroFactor * r on the pole angle (0.9 = authentic;
+    // lower = more pronounced peak, toward 1 the zeros cancel the resonance)
+    // Pole from resonant frequency and Q (common cutoff/Q parameterization):
+    // theta = 2pi f / fs, r = exp(-theta / 2Q)  (bandwidth f/Q)
+    inline PolePair poleFromFreqQ(float freqHz, float q, float sampleRate) noexcept
+    {
+        const float theta = std::clamp(6.283185307f * freqHz / std::max(1.0f, sampleRate), 0.0f, 3.14159265f);
+        const float r = std::exp(-theta / (2.0f * std::max(1.0e-3f, q)));
+        return { std::min(r, MAX_POLE_RADIUS), theta };
+    }
+
+    // Filter family via zero placement. Bandpass: zeros inside the pole (authentic, see zeroFactor),
+    // Lowpass: both zeros at z = -1, Highpass: both at z = +1, Notch: on the unit circle at the pole angle
+    enum class ZeroMode { Bandpass, Lowpass, Highpass, Notch };
+
+    inline void poleToBiquad(const PolePair& p, float& a1, float& a2, float& b0, float& b1, float& b2,
+                             float zeroFactor = 0.9f, ZeroMode mode = ZeroMode::Bandpass) noexcept
     {
         a1 = -2.0f * p.r * std::cos(p.theta);
         a2 = p.r * p.r;
 
-        const float rz = std::clamp(0.9f * p.r, 0.0f, 0.999f);
-        const float c  = std::cos(p.theta);
+        const float c = std::cos(p.theta);
+        const float denDc = 1.0f + a1 + a2, denNyq = 1.0f - a1 + a2;
         b0 = 1.0f;
-        b1 = -2.0f * rz * c;
-        b2 = rz * rz;
+        float norm = 1.0f;
+        switch (mode)
+        {
+            // Non-bandpass families: unity gain in the passband, so the resonance is the only boost
+            case ZeroMode::Lowpass:  b1 =  2.0f; b2 = 1.0f; norm = denDc / 4.0f;  break;
+            case ZeroMode::Highpass: b1 = -2.0f; b2 = 1.0f; norm = denNyq / 4.0f; break;
+            case ZeroMode::Notch:
+                b1 = -2.0f * c; b2 = 1.0f;
+                norm = (c > 0.0f) ? denNyq / (2.0f + 2.0f * c) : denDc / (2.0f - 2.0f * c); // normalize at the far end
+                break;
+            case ZeroMode::Bandpass:
+            default:
+            {
+                const float rz = std::clamp(zeroFactor * p.r, 0.0f, 0.999f);
+                b1 = -2.0f * rz * c;
+                b2 = rz * rz;
+                norm = 1.0f / std::max(0.25f, std::abs(b0) + std::abs(b1) + std::abs(b2));
+                break;
+            }
+        }
 
-        const float norm = 1.0f / std::max(0.25f, std::abs(b0) + std::abs(b1) + std::abs(b2));
         b0 *= norm; b1 *= norm; b2 *= norm;
     }
 
+    // Move a biquad designed at fromFs to toFs with the same analog response (bilinear to bilinear):
+    // z_from^-1 = (alpha + z_to^-1) / (1 + alpha z_to^-1), alpha = (fromFs - toFs) / (fromFs + toFs)
+    inline void biquadToRate(float& b0, float& b1, float& b2, float& a1, float& a2, double fromFs, double toFs) noexcept
+    {
+        const double al = (fromFs - toFs) / (fromFs + toFs), al2 = al * al;
+        const auto map = [al, al2](double c0, double c1, double c2, double& o0, double& o1, double& o2)
+        {
+            o0 = c0 + c1 * al + c2 * al2;
+            o1 = 2.0 * al * (c0 + c2) + c1 * (1.0 + al2);
+            o2 = c0 * al2 + c1 * al + c2;
+        };
+
+        double n0, n1, n2, d0, d1, d2;
+        map(b0, b1, b2, n0, n1, n2);
+        map(1.0, a1, a2, d0, d1, d2);
+        b0 = static_cast<float>(n0 / d0); b1 = static_cast<float>(n1 / d0); b2 = static_cast<float>(n2 / d0);
+        a1 = static_cast<float>(d1 / d0); a2 = static_cast<float>(d2 / d0);
+    }
+
     template <size_t N>
     inline void loadShape(const std::array<float, N>& shape, std::array<PolePair, N/2>& out) noexcept
     {
@@ -160,116 +598,2072 @@ namespace emu
         }
     }
 
-    struct ZPlaneFilter
+    // Checked shape loading for runtime/user data (loadShape trusts its input)
+    enum class ShapeError { None, RadiusOutOfRange, NonFiniteTheta };
+
+    struct ShapeLoadStatus
     {
-        static constexpr int NumSections = 6;
+        ShapeError error{ShapeError::None};
+        int pairIndex{-1}; // first offending pole pair
 
-        void prepare(double sampleRate, int /*samplesPerBlock*/)
+        bool ok() const noexcept { return error == ShapeError::None; }
+
+        const char* message() const noexcept
         {
-            sr = sampleRate;
-            cascadeL.reset();
-            cascadeR.reset();
-            morphSmooth.reset(sr, 0.02);
-            driveSmooth.reset(sr, 0.01);
-            intensitySmooth.reset(sr, 0.02);
-            mixSmooth.reset(sr, 0.02);
+            switch (error)
+            {
+                case ShapeError::RadiusOutOfRange: return "pole radius must be finite and in [0, 1)";
+                case ShapeError::NonFiniteTheta:   return "pole angle must be finite";
+                case ShapeError::None:             break;
+            }
+            return "ok";
         }
+    };
 
-        void setShapePair(const std::array<float,12>& a, const std::array<float,12>& b) noexcept
+    // Validates every pair before writing; `out` is left untouched on error
+    template <size_t N>
+    inline ShapeLoadStatus tryLoadShape(const std::array<float, N>& shape, std::array<PolePair, N/2>& out) noexcept
+    {
+        for (size_t i = 0; i < N/2; ++i)
         {
-            shapeA = a; shapeB = b;
-            loadShape(shapeA, polesA);
-            loadShape(shapeB, polesB);
+            const float r = shape[2*i], theta = shape[2*i + 1];
+            if (!isValidPoleRadius(r))
+                return { ShapeError::RadiusOutOfRange, static_cast<int>(i) };
+            if (!isValidPoleAngle(theta))
+                return { ShapeError::NonFiniteTheta, static_cast<int>(i) };
         }
+        loadShape(shape, out);
+        return {};
+    }
 
-        void setMorph(float m) noexcept { morphSmooth.setTargetValue(std::clamp(m, 0.0f, 1.0f)); }
-        void setIntensity(float i) noexcept { intensitySmooth.setTargetValue(std::clamp(i, 0.0f, 1.0f)); }
-        void setDrive(float d) noexcept { driveSmooth.setTargetValue(std::clamp(d, 0.0f, 1.0f)); }
-        void setSectionSaturation(float s) noexcept { for (auto& sct : cascadeL.sections) sct.setSaturation(s);
-                                                      for (auto& sct : cascadeR.sections) sct.setSaturation(s); }
-        void setMix(float m) noexcept { mixSmooth.setTargetValue(std::clamp(m, 0.0f, 1.0f)); }
+    // Sample-rate handling of the 48k reference poles
+    // Bilinear: preserve absolute Hz (default), FrequencyScale: keep theta, so resonances scale with fs
+    enum class RemapMode { Bilinear, FrequencyScale };
 
-        void reset() { cascadeL.reset(); cascadeR.reset(); morphSmooth.setCurrentAndTargetValue(0.5f); }
+    // Dry reference for the mix: the raw input, or the input after the input trim
+    enum class DryTap { RawInput, PostInputGain };
 
-        // Update coefficients once per block
-        void updateCoeffsBlock(int samplesPerBlock)
+    // Morph range handling: Clamp to [0,1] (default), or Extrapolate the pole trajectory
+    // up to MORPH_EXTRAPOLATION beyond either shape (radius/angle still clamped for stability)
+    enum class MorphClamp { Clamp, Extrapolate };
+    inline constexpr float MORPH_EXTRAPOLATION = 0.5f;
+
+    // What prepare() does with running filter state when the host reconfigures
+    // Reset: clear state (default), Preserve: keep state, SoftFade: clear state and fade the wet path back in
+    enum class ReconfigPolicy { Reset, Preserve, SoftFade };
+
+    // Result of validateShapeForSr: indices of poles that exceed the safe radius
+    struct ShapeValidation
+    {
+        bool ok() const noexcept { return numUnsafe == 0; }
+
+        int numUnsafe{0};
+        std::array<int, 6> unsafeIndices{};
+    };
+
+    // Run each pole of a 12-float shape through remap + intensity boost and flag those that
+    // land above MAX_POLE_RADIUS (they would be clamped) or become non-finite
+    inline ShapeValidation validateShapeForSr(const std::array<float, 12>& shape, double sampleRate, float intensity) noexcept
+    {
+        ShapeValidation result;
+        const float intensityBoost = 1.0f + std::clamp(intensity, 0.0f, 1.0f) * 0.06f;
+        for (int i = 0; i < 6; ++i)
         {
-            // Advance smoothers by block size for per-sample effective stepping
-            // (Note: these are only read once per block, but skip() ensures proper settling time)
-            morphSmooth.skip(samplesPerBlock);
-            intensitySmooth.skip(samplesPerBlock);
+            const PolePair p = remapPole48kToFs(PolePair{ shape[static_cast<size_t>(2 * i)], shape[static_cast<size_t>(2 * i + 1)] }, sampleRate);
+            const float r = p.r * intensityBoost;
+            if (!std::isfinite(r) || !std::isfinite(p.theta) || r > MAX_POLE_RADIUS)
+                result.unsafeIndices[static_cast<size_t>(result.numUnsafe++)] = i;
+        }
+        return result;
+    }
 
-            lastMorph     = morphSmooth.getCurrentValue();
-            lastIntensity = intensitySmooth.getCurrentValue();
+    // Stereo processing mode: filter L/R independently, only the mid/side component, or mid on the left
+    // cascade and side on the right (MidSide; the dry/wet mix stays in L/R against the true dry)
+    enum class MsMode { Stereo, MidOnly, SideOnly, MidSide };
 
-            const float intensityBoost = 1.0f + lastIntensity * 0.06f; // AUTHENTIC scaling
+    // Oversampling sub-mode: the whole drive + cascade path, or only the band above a crossover through
+    // the drive (the low band and the cascade stay at the base rate)
+    enum class OversamplingMode { FullBand, Crossover };
 
-            for (int i = 0; i < NumSections; ++i)
-            {
-                // 1) Interpolate in 48k reference domain (geodesic or linear)
-                PolePair p48k = interpolatePole(polesA[i], polesB[i], lastMorph);
+    // Rumble high-pass placement: before drive (no LF intermodulation) or after (also removes drive DC)
+    enum class HpPosition { PreDrive, PostDrive };
+
+    // Per-stage enable flags for debugging/routing (all on = normal processing)
+    struct PipelineConfig
+    {
+        enum Stage : uint32_t
+        {
+            InputGain  = 1u << 0,
+            Drive      = 1u << 1,
+            Cascade    = 1u << 2,
+            Saturation = 1u << 3, // per-section tanh
+            Comb       = 1u << 4,
+            Mix        = 1u << 5, // off = 100% wet
+            InputHighPass = 1u << 6, // DC blocker and rumble high-pass
+            OutputGain = 1u << 7, // morph makeup gain
+            All        = 0xFFu
+        };
+
+        uint32_t stages{All};
+
+        bool has(Stage s) const noexcept { return (stages & s) != 0; }
+    };
+
+    // Snapshot of the glideable filter settings (see ZPlaneFilter::morphToState)
+    struct FilterState
+    {
+        float morph{0.5f};
+        float intensity{AUTHENTIC_INTENSITY};
+        float drive{AUTHENTIC_DRIVE};
+        float saturation{AUTHENTIC_SATURATION};
+    };
+
+    // Result of ZPlaneFilter::selfTest(): failing grid points (first MaxFailures recorded)
+    struct SelfTestReport
+    {
+        struct Failure { float morph, intensity, drive; bool nonFinite, noDecay; };
+        static constexpr int MaxFailures = 32;
 
-                // 2) Bilinear remap from 48k to actual sample rate
-                PolePair pm = remapPole48kToFs(p48k, sr);
+        bool passed() const noexcept { return numFailed == 0; }
+
+        int numTested{0};
+        int numFailed{0};
+        std::array<Failure, MaxFailures> failures{};
+    };
+
+    // N cascaded pole-pair sections (order 2N); ZPlaneFilter below is the authentic 6-section instance
+    template <size_t N>
+    struct ZPlaneFilterN
+    {
+        static constexpr int NumSections = static_cast<int>(N);
+        using ShapeArray = std::array<float, 2 * N>;
 
-                // 3) Apply intensity boost and EMU hardware clamp
-                pm.r = std::min(pm.r * intensityBoost, MAX_POLE_RADIUS);
+        // Morph-linked makeup gain table (dB), morph x intensity
+        static constexpr int MakeupMorphPoints = 33;     // 1/16 morph steps across [-0.5, 1.5]
+        static constexpr int MakeupIntensityPoints = 17;
+        using MakeupTable = std::array<float, MakeupMorphPoints * MakeupIntensityPoints>;
 
-                lastInterpPoles[i] = pm;
+        void prepare(double sampleRate, int /*samplesPerBlock*/)
+        {
+            sr = sampleRate;
+            if (reconfigPolicy != ReconfigPolicy::Preserve)
+            {
+                cascadeL.reset();
+                cascadeR.reset();
+                resetOversampling();
+                resetDecorrelation();
             }
+            reconfigFade.reset(sr, 0.01);
+            reconfigFade.setCurrentAndTargetValue(reconfigPolicy == ReconfigPolicy::SoftFade ? 0.0f : 1.0f);
+            reconfigFade.setTargetValue(1.0f);
+            resetMorphIntensitySmoothers();
+            driveSmooth.reset(sr, 0.01);
+            mixSmooth.reset(sr, 0.02);
+            inputGainSmooth.reset(sr, 0.02);
+            morphGainL.reset(sr, 0.02);
+            morphGainR.reset(sr, 0.02);
+            gridCounter = 0;
+            samplesSinceCoeffUpdate = coeffInterval;
+            coeffsDirty = true;
 
-            for (int ch = 0; ch < 2; ++ch)
+            setRumbleHighpass(rumbleHz);
+            dcBlockL.R = dcBlockR.R = static_cast<float>(std::exp(-juce::MathConstants<double>::twoPi * DcBlockHz / sr));
+            if (reconfigPolicy != ReconfigPolicy::Preserve)
             {
-                auto& cas = (ch == 0 ? cascadeL : cascadeR);
-                for (int i = 0; i < NumSections; ++i)
-                {
-                    float a1, a2, b0, b1, b2;
-                    poleToBiquad(lastInterpPoles[i], a1, a2, b0, b1, b2);
-                    cas.sections[(size_t)i].setCoeffs(b0, b1, b2, a1, a2);
-                }
+                dcBlockL.reset();
+                dcBlockR.reset();
+            }
+            setLimiterTiming(limiterAttackMs, limiterReleaseMs);
+            limiterEnv = 0.0f;
+            if (reconfigPolicy != ReconfigPolicy::Preserve)
+            {
+                rumbleL.reset();
+                rumbleR.reset();
             }
+
+            xoverL.setCutoff(xoverHz, sr);
+            xoverR.setCutoff(xoverHz, sr);
+
+            for (auto* v : { &lpFwdL, &lpFwdR, &lpDryL, &lpDryR })
+                v->assign(2 * LinearPhaseFrame, 0.0f);
+            lpOutL.assign(LinearPhaseFrame, 0.0f);
+            lpOutR.assign(LinearPhaseFrame, 0.0f);
+            lpWrite = lpPos = 0;
+
+            const int maxCombDelay = static_cast<int>(std::ceil(sr / MinCombHz));
+            combL.prepare(maxCombDelay);
+            combR.prepare(maxCombDelay);
+            setComb(combHz, combFeedback);
+            setSectionAllpassDecorrelation(decorrelation);
+            if (morphGainComp)
+                rebuildMakeupTable();
         }
 
-        // Get last interpolated poles (for UI visualization)
-        const std::array<PolePair, NumSections>& getLastPoles() const noexcept { return lastInterpPoles; }
+        // Reloads both pole sets and forces a recompute on the next coefficient update. Cascade state
+        // (z1/z2) is kept, so swapping mid-stream does not click. With morph gain compensation on this
+        // also rebuilds the makeup table, so it is not for the audio thread there: live swaps go
+        // through the registered-pair overload, which reads a precomputed table
+        void setShapePair(const ShapeArray& a, const ShapeArray& b,
+                          const char* name = "Custom") noexcept
+        {
+            loadShapes(a, b, name);
+            if (morphGainComp)
+                rebuildMakeupTable();
+        }
 
-        // Process block (stereo)
-        void process(float* left, float* right, int num)
+        // Bipolar character: morph 0.5 is the center shape, below it morphs center -> left (reached at 0),
+        // above it center -> right (reached at 1). setShapePair returns to the regular A -> B morph.
+        // Rebuilds the makeup table when morph gain compensation is on: not for the audio thread there
+        void setBipolarShapes(const ShapeArray& left, const ShapeArray& center, const ShapeArray& right,
+                              const char* name = "Bipolar") noexcept
         {
-            for (int n = 0; n < num; ++n)
+            setShapeName(name);
+            shapeA = left; shapeB = right;
+            shapesIdentical = false;
+            bipolar = true;
+            loadShape(left, polesA);
+            loadShape(right, polesB);
+            loadShape(center, polesCenter);
+            coeffsDirty = true;
+            if (morphGainComp)
+                rebuildMakeupTable();
+        }
+
+        // Registered pair: shapes, display name and the pair's intensity response curve (6-section filters).
+        // Live swap: a built-in pair copies its makeup table, precomputed by rebuildMakeupTable, so this is
+        // safe on the audio thread; other pairs rebuild it like the raw-shape overload
+        void setShapePair(const ShapePair& pair) noexcept
+        {
+            loadShapes(*pair.a, *pair.b, pair.name);
+            setIntensityCurve(pair.intensityCurve);
+            if (! morphGainComp)
+                return;
+            if (const auto index = builtInPairIndex(pair); index < SHAPE_PAIRS.size())
+                makeupTableDb = pairMakeupTableDb[index];
+            else
+                rebuildMakeupTable();
+        }
+
+        // Exponent applied to intensity before the radius boost (1 = authentic linear response)
+        void setIntensityCurve(float exponent) noexcept { intensityCurve = std::clamp(exponent, 0.1f, 10.0f); coeffsDirty = true; }
+
+        void setMorph(float m) noexcept
+        {
+            releaseStereoMorphTargets();
+            morphSmooth.setTargetValue(clampMorph(m));
+        }
+
+        // Back to the shared morph (+ spread / right offset) after updateCoeffsStereo
+        void releaseStereoMorphTargets() noexcept
+        {
+            if (stereoMorphTargets)
             {
-                // Advance smoothers per-sample for proper 20ms ramps
-                const float drive = driveSmooth.getNextValue();
-                const float mix   = mixSmooth.getNextValue();
+                stereoMorphTargets = false;
+                coeffsDirty = true;
+            }
+        }
+        void setMorphClamp(MorphClamp c) noexcept { morphClamp = c; coeffsDirty = true; }
 
-                const float driveGain = 1.0f + drive * 4.0f;
+        float clampMorph(float m) const noexcept
+        {
+            const float ext = (morphClamp == MorphClamp::Extrapolate) ? MORPH_EXTRAPOLATION : 0.0f;
+            return std::clamp(m, -ext, 1.0f + ext);
+        }
+        void setIntensity(float i) noexcept { intensitySmooth.setTargetValue(std::clamp(i, 0.0f, 1.0f)); }
 
-                // Capture true dry input BEFORE any processing
-                const float inL = left[n];
-                const float inR = right[n];
+        // Extra one-pole smoothing (time constant in ms, 0 = off) on top of the linear morph/intensity
+        // ramps, advanced per block in updateCoeffsBlock so coarse automation glides instead of stepping.
+        // Block-rate coefficient mode only (no sample-count smoothing or sub-block quality)
+        void setMorphSmoothingMs(float ms) noexcept
+        {
+            morphSmoothingMs = std::max(0.0f, ms);
+            snapMorphPole();
+        }
 
-                // Pre-drive (authentic: tanh on input)
-                float l = std::tanh(inL * driveGain);
-                float r = std::tanh(inR * driveGain);
+        void snapMorphPole() noexcept
+        {
+            morphPole = morphSmooth.getCurrentValue();
+            intensityPole = intensitySmooth.getCurrentValue();
+        }
+        void setDrive(float d) noexcept { driveSmooth.setTargetValue(std::clamp(d, 0.0f, 1.0f)); }
+        void setSectionSaturation(float s) noexcept { saturation = std::clamp(s, 0.0f, 1.0f);
+                                                      const float applied = pipeline.has(PipelineConfig::Saturation) ? s : 0.0f;
+                                                      for (auto& sct : cascadeL.sections) sct.setSaturation(applied);
+                                                      for (auto& sct : cascadeR.sections) sct.setSaturation(applied);
+                                                      for (auto& sct : cascadeOsL.sections) sct.setSaturation(applied);
+                                                      for (auto& sct : cascadeOsR.sections) sct.setSaturation(applied); }
+        void setMix(float m) noexcept { mixSmooth.setTargetValue(std::clamp(m, 0.0f, 1.0f)); }
+        void setInputGain(float linear) noexcept { inputGainSmooth.setTargetValue(std::max(0.0f, linear)); }
+        void setDryTap(DryTap t) noexcept { dryTap = t; }
+        void setMsMode(MsMode m) noexcept { msMode = m; }
+        void setGeodesicRadius(bool on) noexcept { geodesicRadius = on; coeffsDirty = true; }
+        void setDenormalStrategy(DenormalStrategy d) noexcept { for (auto& sct : cascadeL.sections) sct.denormal = d;
+                                                                for (auto& sct : cascadeR.sections) sct.denormal = d;
+                                                                for (auto& sct : cascadeOsL.sections) sct.denormal = d;
+                                                                for (auto& sct : cascadeOsR.sections) sct.denormal = d; }
+        void setDriveFollowsMix(bool on) noexcept { driveFollowsMix = on; }
+        void setMixEndpointSmoothing(float amount) noexcept { mixEndpointSmoothing = std::clamp(amount, 0.0f, 1.0f); mixGainMix = -1.0f; }
+        void setRadiusCombine(RadiusRule r) noexcept { radiusRule = r; coeffsDirty = true; }
+        void setRemapMode(RemapMode m) noexcept { remapMode = m; coeffsDirty = true; }
+        void setSrIntensityComp(bool on) noexcept { srIntensityComp = on; coeffsDirty = true; }
 
-                float wetL = cascadeL.process(l);
-                float wetR = cascadeR.process(r);
+        // DC blocker on the wet path input (off by default, so existing settings sound unchanged): stops resonant low shapes from building up
+        // offset on sustained material. The dry signal is left untouched
+        void setDcBlock(bool on) noexcept { dcBlock = on; }
 
-                // Mix (equal-power to avoid perceived dips around 50% and preserve tone with nonlinearities)
-                // Use TRUE dry signal (inL/inR) not driven signal for authentic bypass tone
-                const float wetG = std::sqrt(mix);
-                const float dryG = std::sqrt(1.0f - mix);
-                left[n]  = wetL * wetG + inL * dryG;
-                right[n] = wetR * wetG + inR * dryG;
+        // Morph-linked makeup gain: scales the wet signal by the inverse white-noise power gain of the
+        // current (linear) response, so loudness stays level across the whole morph sweep. Smoothed over
+        // 20 ms, limited to ±MaxMorphGainDb. The gains come from a morph x intensity table of impulse
+        // probes, built here, in prepare and on raw shape changes (built-in pairs swap in tables built
+        // alongside); coefficient updates only interpolate it. Other response settings (tuning, zeros,
+        // remap) are picked up at the next rebuild
+        void setMorphGainComp(bool on) noexcept
+        {
+            morphGainComp = on;
+            if (on)
+                rebuildMakeupTable();
+            else
+            {
+                morphGainL.setTargetValue(1.0f);
+                morphGainR.setTargetValue(1.0f);
             }
+            coeffsDirty = true;
         }
 
-        double sr { REFERENCE_SR };
+        // Probes the linear response at every makeup table point (MakeupMorphPoints x MakeupIntensityPoints
+        // impulse responses of MakeupProbeSamples each), for the current shapes and for every built-in
+        // pair (6-section filters), so later setShapePair(ShapePair) calls only copy. Not for the audio thread
+        void rebuildMakeupTable() noexcept
+        {
+            fillMakeupTable(makeupTableDb);
+
+            if constexpr (std::is_same_v<ShapeArray, Shape>)
+            {
+                // Borrow the pole sets: every other response setting applies to the pair tables as well
+                const auto savedA = polesA, savedB = polesB;
+                const bool savedIdentical = shapesIdentical, savedBipolar = bipolar;
+                const float savedCurve = intensityCurve;
+                for (size_t p = 0; p < SHAPE_PAIRS.size(); ++p)
+                {
+                    const auto& pair = SHAPE_PAIRS[p];
+                    loadShape(*pair.a, polesA);
+                    loadShape(*pair.b, polesB);
+                    shapesIdentical = (*pair.a == *pair.b);
+                    bipolar = false;
+                    intensityCurve = pair.intensityCurve;
+                    fillMakeupTable(pairMakeupTableDb[p]);
+                }
+                polesA = savedA; polesB = savedB;
+                shapesIdentical = savedIdentical; bipolar = savedBipolar;
+                intensityCurve = savedCurve;
+            }
+        }
+
+        // Seed for every stochastic component. Each instance gets its own at construction (so parallel
+        // instances stay decorrelated); set it explicitly for reproducible renders/tests
+        void setSeed(uint64_t s) noexcept { seed = s; noise.seed(s); }
+        uint64_t getSeed() const noexcept { return seed; }
+        float nextNoise() noexcept { return noise.next(); }
+
+        // Rumble high-pass on the filter input (0 Hz = off), placed before or after the pre-drive
+        void setRumbleHighpass(float freqHz) noexcept
+        {
+            rumbleHz = std::max(0.0f, freqHz);
+            rumbleL.setCutoff(rumbleHz, sr);
+            rumbleR.setCutoff(rumbleHz, sr);
+        }
+
+        void setHpPosition(HpPosition p) noexcept { hpPosition = p; }
+
+        // Mix-bus limiter dynamics: with attack/release > 0 a linked peak envelope pulls the bus down to
+        // the knee (transparent gain riding); the soft clip then only catches attack overshoot.
+        // 0/0 = instantaneous soft clipping only
+        void setLimiterTiming(float attackMs, float releaseMs) noexcept
+        {
+            limiterAttackMs  = std::max(0.0f, attackMs);
+            limiterReleaseMs = std::max(0.0f, releaseMs);
+            const auto coef = [this](float ms) { return ms > 0.0f ? static_cast<float>(std::exp(-1.0 / (0.001 * ms * sr))) : 0.0f; };
+            limiterAttackCoef  = coef(limiterAttackMs);
+            limiterReleaseCoef = coef(limiterReleaseMs);
+        }
+
+        // Zero-phase (forward-backward) wet path for offline/bounce use. Each LinearPhaseFrame of forward
+        // output is run backwards through a copy of the cascade (with one frame of warm-up), so transients
+        // are not smeared. Costs roughly 2-3x the cascade CPU (in bursts at frame boundaries), squares the
+        // magnitude response, and delays wet and dry by latencySamples(). Stereo mode only (MsMode::Stereo)
+        void setLinearPhase(bool on) noexcept
+        {
+            linearPhase = on;
+            resetLinearPhase();
+        }
+
+        // Soft clipper on the summed wet/dry bus (0 = off). The knee drops from 1.0 to 0.5 with
+        // amount; the output stays bounded to ±1 and signals below the knee pass untouched
+        void setMixbusClip(float amount) noexcept
+        {
+            mixbusClip = std::clamp(amount, 0.0f, 1.0f);
+            mixbusKnee = 1.0f - 0.5f * mixbusClip;
+        }
+
+        // Build-up mode: resonances join one at a time as morph increases (see revealWeight)
+        void setProgressiveReveal(bool on) noexcept { progressiveReveal = on; coeffsDirty = true; }
+
+        // Per-section frequency multipliers applied to the pole angles (all 1 = shape as authored)
+        void setInharmonicity(const std::array<float, NumSections>& ratios) noexcept
+        {
+            for (size_t i = 0; i < NumSections; ++i)
+                inharmonicity[i] = std::max(0.0f, ratios[i]);
+            coeffsDirty = true;
+        }
+
+        // Global tune: shifts every pole angle by 2^(semitones/12) in the 48k reference domain (before remap)
+        void setFrequencyShift(float semitones) noexcept
+        {
+            frequencyShift = std::pow(2.0f, std::clamp(semitones, -48.0f, 48.0f) / 12.0f);
+            coeffsDirty = true;
+        }
+
+        // Pole spacing around the mean angle: > 1 spreads the resonances apart, < 1 pulls them together
+        void setFormantStretch(float factor) noexcept
+        {
+            formantStretch = std::clamp(factor, 0.0f, 4.0f);
+            coeffsDirty = true;
+        }
+
+        void setZeroMode(ZeroMode m) noexcept { zeroMode = m; coeffsDirty = true; }
+
+        // Zero placement per shape (see poleToBiquad), interpolated by morph; 0.9/0.9 = authentic
+        void setZeroFactorPair(float a, float b) noexcept
+        {
+            zeroFactorA = std::clamp(a, 0.0f, 1.0f);
+            zeroFactorB = std::clamp(b, 0.0f, 1.0f);
+            coeffsDirty = true;
+        }
+        void setReconfigPolicy(ReconfigPolicy p) noexcept { reconfigPolicy = p; }
+        void setPipeline(PipelineConfig cfg) noexcept { pipeline = cfg; setSectionSaturation(saturation); }
+
+        int latencySamples() const noexcept { return (linearPhase ? 2 * LinearPhaseFrame : 0) + osL.latencySamples(); }
+
+        // Oversampled drive + cascade (1 = off, 2, 4): the pre-drive tanh and per-section saturation run
+        // at factor x the rate (cascade coefficients are derived for that rate), then half-band decimated.
+        // Adds osL.latencySamples() (15 at 2x, 23 at 4x) to latencySamples(); the dry path is delayed to
+        // match. Stereo or MidSide mode without decorrelation or the frozen snapshot only; otherwise base rate
+        void setOversampling(int factor) noexcept
+        {
+            osL.setFactor(factor);
+            osR.setFactor(factor);
+            setSectionSaturation(saturation);
+            resetOversampling();
+            coeffsDirty = true;
+        }
+
+        int oversamplingFactor() const noexcept { return osL.factor(); }
+
+        // Blocks with drive and saturation both at or below `threshold` skip the oversampled path
+        // (the wet signal is delayed instead, so latency stays constant)
+        void setOversamplingBypassThreshold(float threshold) noexcept { osBypassThreshold = std::max(0.0f, threshold); }
+
+        // Crossover sub-mode: a Linkwitz-Riley split at crossoverHz; only the high band is driven at the
+        // oversampled rate, the low band is driven at the base rate (delayed to stay aligned) and the
+        // summed bands run through the base-rate cascade. Cheaper than FullBand, which also oversamples
+        // the cascade, at the cost of driving the bands separately and leaving section saturation at the
+        // base rate. Same latency as FullBand
+        void setOversamplingMode(OversamplingMode mode, float crossoverHz = 4000.0f) noexcept
+        {
+            osMode = mode;
+            xoverHz = std::max(20.0f, crossoverHz);
+            xoverL.setCutoff(xoverHz, sr);
+            xoverR.setCutoff(xoverHz, sr);
+            resetOversampling();
+            coeffsDirty = true;
+        }
+
+        OversamplingMode oversamplingMode() const noexcept { return osMode; }
+
+        // Morph/intensity ramp length in samples (0 = legacy per-block smoothing)
+        // When > 0 the smoothers advance per sample inside process() and coefficients are
+        // refreshed on a fixed CoeffGridSamples grid, so output is independent of host block size
+        void setSmoothingSamples(int samples) noexcept
+        {
+            smoothingSamples = std::max(0, samples);
+            resetMorphIntensitySmoothers();
+            gridCounter = 0;
+        }
+
+        // Rate-limit coefficient recomputation to at most once per `samples` of processing
+        // (0 = every block). New coefficients are ramped in linearly over the interval.
+        void setCoeffUpdateInterval(int samples) noexcept
+        {
+            coeffInterval = std::max(0, samples);
+            samplesSinceCoeffUpdate = coeffInterval;
+        }
+
+        // Clean restart: every stage's signal state (cascades, delays, ring buffers, envelopes, fades) is cleared
+        void reset()
+        {
+            cascadeL.reset(); cascadeR.reset();
+            resetOversampling();
+            resetDecorrelation();
+            resetLinearPhase();
+            combL.reset(); combR.reset();
+            rumbleL.reset(); rumbleR.reset();
+            dcBlockL.reset(); dcBlockR.reset();
+            alignL.reset(); alignR.reset();
+            limiterEnv = 0.0f;
+            reconfigFade.setCurrentAndTargetValue(1.0f);
+            stereoMorphTargets = false;
+            morphSmooth.setCurrentAndTargetValue(0.5f);
+            snapMorphPole();
+            gridCounter = 0;
+            samplesSinceCoeffUpdate = coeffInterval;
+        }
+
+        // Diagnostics only (not for the audio thread): load known (z1, z2) pairs into the base-rate cascades,
+        // e.g. to reproduce a captured state. Everything else keeps its current state
+        void seedState(const std::array<std::pair<float, float>, NumSections>& left,
+                       const std::array<std::pair<float, float>, NumSections>& right) noexcept
+        {
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                cascadeL.sections[i].setState(left[i].first, left[i].second);
+                cascadeR.sections[i].setState(right[i].first, right[i].second);
+            }
+        }
+
+        FilterState getState() const noexcept
+        {
+            return { morphSmooth.getCurrentValue(), intensitySmooth.getCurrentValue(),
+                     driveSmooth.getCurrentValue(), saturation };
+        }
+
+        // Glide morph/intensity/drive/saturation from the current settings to `target` over `ms`,
+        // stepped once per block in updateCoeffsBlock (overrides the regular smoothers meanwhile)
+        void morphToState(const FilterState& target, float ms) noexcept
+        {
+            glideFrom = getState();
+            glideTo = target;
+            glideTotal = std::max(1, static_cast<int>(ms * 0.001 * sr));
+            glideElapsed = 0;
+            releaseStereoMorphTargets();
+        }
+
+        bool isGliding() const noexcept { return glideElapsed < glideTotal; }
+
+        // Set morph/intensity targets and update coefficients for the next numSamples
+        void updateCoeffsBlock(float targetMorph, float targetIntensity, int numSamples)
+        {
+            setMorph(targetMorph);
+            setIntensity(targetIntensity);
+            updateCoeffsBlock(numSamples);
+        }
+
+        // Update coefficients once per block
+        void updateCoeffsBlock(int samplesPerBlock)
+        {
+            if (isGliding())
+                advanceStateGlide(samplesPerBlock);
+
+            // Sample-count smoothing: coefficients are driven from process() instead
+            if (smoothingSamples > 0)
+                return;
+
+            // Sub-block updates: process() steps the smoothers and recomputes every subBlockLen samples
+            if (coeffQuality > 0.0f)
+            {
+                const int updates = coeffUpdatesPerBlock(samplesPerBlock);
+                subBlockLen = (std::max(1, samplesPerBlock) + updates - 1) / updates;
+                subBlockCounter = 0;
+                return;
+            }
+
+            // Advance smoothers by block size for per-sample effective stepping
+            // (Note: these are only read once per block, but skip() ensures proper settling time)
+            morphSmooth.skip(samplesPerBlock);
+            intensitySmooth.skip(samplesPerBlock);
+            if (morphSmoothingMs > 0.0f)
+                advanceMorphPole(samplesPerBlock);
+
+            if (coeffInterval > 0)
+            {
+                samplesSinceCoeffUpdate += samplesPerBlock;
+                if (samplesSinceCoeffUpdate < coeffInterval)
+                    return;
+                samplesSinceCoeffUpdate = 0;
+            }
+
+            // Static fast path: nothing moved since the last recompute (prepare/setters mark coeffsDirty)
+            if (!coeffsDirty && coeffMorph() == lastMorph
+                && coeffIntensity() == lastIntensity && rightMorphOffset == lastRightMorphOffset)
+                return;
+
+            if (skipThreshold > 0.0f && canSkipCoeffs(samplesPerBlock))
+            {
+                ++skippedCoeffUpdates;
+                return;
+            }
+
+            computeCoeffs();
+        }
+
+        // Block-size-aware coefficient granularity (0 = one update per block). Updates per block grow
+        // with sqrt(blockSize), so large blocks get finer steps and tiny blocks avoid redundant recomputes
+        void setCoeffQuality(float quality) noexcept
+        {
+            coeffQuality = std::clamp(quality, 0.0f, 1.0f);
+            if (coeffQuality <= 0.0f)
+                subBlockLen = 0;
+        }
+
+        int coeffUpdatesPerBlock(int blockSize) const noexcept
+        {
+            if (coeffQuality <= 0.0f) return 1;
+            const int n = static_cast<int>(std::lround(coeffQuality * std::sqrt(static_cast<float>(std::max(1, blockSize)))));
+            return std::clamp(n, 1, std::max(1, blockSize));
+        }
+
+        // Velocity-aware skip: keep the existing coefficients while morph/intensity move slower than
+        // `morphPerSecond` and have drifted less than MaxStaleDelta since the last recompute (0 = off)
+        void setCoeffSkipThreshold(float morphPerSecond) noexcept { skipThreshold = std::max(0.0f, morphPerSecond); }
+
+        // Intensity-only updates reuse the cached pre-intensity poles (same result as the full path).
+        // Off = always re-interpolate; intensityOnlyUpdates counts the recomputes that took the shortcut
+        void setIntensityFastPath(bool on) noexcept { intensityFastPath = on; }
+
+        bool canSkipCoeffs(int samplesPerBlock) noexcept
+        {
+            const float m  = coeffMorph();
+            const float in = coeffIntensity();
+            const float blockSec = static_cast<float>(std::max(1, samplesPerBlock) / sr);
+            const float velocity = std::max(std::abs(m - prevBlockMorph), std::abs(in - prevBlockIntensity)) / blockSec;
+            prevBlockMorph = m;
+            prevBlockIntensity = in;
+
+            const float drift = std::max({ std::abs(m - lastMorph), std::abs(in - lastIntensity),
+                                           std::abs(rightMorphOffset - lastRightMorphOffset) });
+            return !coeffsDirty && velocity < skipThreshold && drift < MaxStaleDelta;
+        }
+
+        // Morph/intensity the coefficients are built from: the one-pole outputs when that smoothing
+        // applies (block-rate mode), otherwise the linear smoothers
+        bool morphPoleActive() const noexcept { return morphSmoothingMs > 0.0f && smoothingSamples == 0 && coeffQuality <= 0.0f; }
+        float coeffMorph() const noexcept { return morphPoleActive() ? morphPole : morphSmooth.getCurrentValue(); }
+        float coeffIntensity() const noexcept { return morphPoleActive() ? intensityPole : intensitySmooth.getCurrentValue(); }
+
+        void advanceMorphPole(int numSamples) noexcept
+        {
+            const float a = 1.0f - static_cast<float>(std::exp(-std::max(0, numSamples) / (0.001 * morphSmoothingMs * sr)));
+            const auto step = [a](float& state, float target)
+            {
+                state += a * (target - state);
+                if (std::abs(target - state) < 1.0e-5f) state = target; // settle so the static fast path kicks in
+            };
+            step(morphPole, morphSmooth.getCurrentValue());
+            step(intensityPole, intensitySmooth.getCurrentValue());
+        }
+
+        // Recompute coefficients from the current morph/intensity smoother values
+        void computeCoeffs()
+        {
+            // Intensity-only change: the cached pre-intensity poles are still valid, skip interpolation + remap
+            const bool intensityOnly = intensityFastPath && !coeffsDirty
+                                    && coeffMorph() == lastMorph && rightMorphOffset == lastRightMorphOffset;
+
+            lastMorph     = coeffMorph();
+            lastIntensity = coeffIntensity();
+            lastRightMorphOffset = rightMorphOffset;
+            coeffsDirty = false;
+
+            // Stereo spread: L/R morph offset symmetrically around the shared morph, unless
+            // updateCoeffsStereo pinned explicit per-channel targets
+            const float half = 0.5f * stereoSpread;
+            const float morphL = stereoMorphTargets ? stereoMorphL : clampMorph(lastMorph - half);
+            const float morphR = stereoMorphTargets ? stereoMorphR : clampMorph(lastMorph + half + rightMorphOffset);
+            const bool split = stereoMorphTargets ? morphL != morphR : stereoSpread > 0.0f || rightMorphOffset != 0.0f;
+            if (intensityOnly)
+            {
+                ++intensityOnlyUpdates;
+            }
+            else
+            {
+                computeBasePoles(morphL, basePolesL);
+                if (split)
+                    computeBasePoles(morphR, basePolesR);
+                else
+                    basePolesR = basePolesL;
+            }
+            applyIntensity(basePolesL, morphL, lastInterpPoles);
+            if (split)
+                applyIntensity(basePolesR, morphR, lastInterpPolesR);
+            else
+                lastInterpPolesR = lastInterpPoles;
+
+            // Frozen response: live poles still track (UI), the cascades keep the snapshot
+            if (useSnapshot)
+                return;
+
+            applyPoles(lastInterpPoles, cascadeL, zeroFactorAt(morphL));
+            applyPoles(lastInterpPolesR, cascadeR, zeroFactorAt(split ? morphR : morphL));
+
+            // Oversampled cascades: the base-rate biquads moved to the oversampled rate, so both
+            // paths share the same analog response (hardware clamp and zero placement included)
+            if (osL.factor() > 1 && osMode == OversamplingMode::FullBand)
+            {
+                const double osRate = sr * osL.factor();
+                applyPoles(lastInterpPoles, cascadeOsL, zeroFactorAt(morphL), osRate);
+                applyPoles(lastInterpPolesR, cascadeOsR, zeroFactorAt(split ? morphR : morphL), osRate);
+            }
+
+            if (spreadAlign)
+                updateSpreadAlignment(zeroFactorAt(morphL), zeroFactorAt(split ? morphR : morphL));
+
+            if (morphGainComp)
+            {
+                const float gL = interpolatedMakeupGain(morphL, lastIntensity);
+                morphGainL.setTargetValue(gL);
+                morphGainR.setTargetValue(split ? interpolatedMakeupGain(morphR, lastIntensity) : gL);
+            }
+        }
+
+        void loadShapes(const ShapeArray& a, const ShapeArray& b, const char* name) noexcept
+        {
+            setShapeName(name);
+            shapeA = a; shapeB = b;
+            shapesIdentical = (a == b);
+            bipolar = false;
+            loadShape(shapeA, polesA);
+            loadShape(shapeB, polesB);
+            coeffsDirty = true;
+        }
+
+        // Index into SHAPE_PAIRS when pair is one of its entries, SHAPE_PAIRS.size() otherwise
+        static size_t builtInPairIndex(const ShapePair& pair) noexcept
+        {
+            for (size_t p = 0; p < SHAPE_PAIRS.size(); ++p)
+                if (&pair == &SHAPE_PAIRS[p])
+                    return p;
+            return SHAPE_PAIRS.size();
+        }
+
+        void fillMakeupTable(MakeupTable& table) const noexcept
+        {
+            std::array<PolePair, NumSections> base, poles;
+            for (int m = 0; m < MakeupMorphPoints; ++m)
+            {
+                const float morph = makeupTableMorph(m);
+                computeBasePoles(morph, base);
+                for (int i = 0; i < MakeupIntensityPoints; ++i)
+                {
+                    applyIntensity(base, morph, static_cast<float>(i) / static_cast<float>(MakeupIntensityPoints - 1), poles);
+                    table[static_cast<size_t>(m * MakeupIntensityPoints + i)]
+                        = juce::Decibels::gainToDecibels(makeupGain(poles, zeroFactorAt(morph)));
+                }
+            }
+        }
+
+        // Makeup table grid: morph spans the full extrapolation range, intensity 0..1
+        static float makeupTableMorph(int index) noexcept
+        {
+            return -MORPH_EXTRAPOLATION + (1.0f + 2.0f * MORPH_EXTRAPOLATION)
+                       * static_cast<float>(index) / static_cast<float>(MakeupMorphPoints - 1);
+        }
+
+        // Bilinear lookup (in dB) into the makeup table
+        float interpolatedMakeupGain(float morph, float intensity) const noexcept
+        {
+            const float mPos = std::clamp((morph + MORPH_EXTRAPOLATION) / (1.0f + 2.0f * MORPH_EXTRAPOLATION), 0.0f, 1.0f)
+                             * static_cast<float>(MakeupMorphPoints - 1);
+            const float iPos = std::clamp(intensity, 0.0f, 1.0f) * static_cast<float>(MakeupIntensityPoints - 1);
+            const int m0 = std::min(static_cast<int>(mPos), MakeupMorphPoints - 2);
+            const int i0 = std::min(static_cast<int>(iPos), MakeupIntensityPoints - 2);
+            const float mf = mPos - static_cast<float>(m0), inf = iPos - static_cast<float>(i0);
+
+            const auto at = [this](int m, int i) { return makeupTableDb[static_cast<size_t>(m * MakeupIntensityPoints + i)]; };
+            const float lo = at(m0, i0)     + inf * (at(m0, i0 + 1)     - at(m0, i0));
+            const float hi = at(m0 + 1, i0) + inf * (at(m0 + 1, i0 + 1) - at(m0 + 1, i0));
+            return juce::Decibels::decibelsToGain(lo + mf * (hi - lo));
+        }
+
+        // 1 / RMS gain for white noise: the impulse response energy of the linear cascade (no saturation).
+        // MAX_POLE_RADIUS keeps the tail below -80 dB within MakeupProbeSamples
+        float makeupGain(const std::array<PolePair, NumSections>& poles, float zeroFactor) const noexcept
+        {
+            BiquadCascade<NumSections> probe;
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                auto& sct = probe.sections[i];
+                sct.passthrough = poles[i].r < passthroughRadius;
+                if (sct.passthrough) continue;
+                float a1, a2, b0, b1, b2;
+                poleToBiquad(poles[i], a1, a2, b0, b1, b2, zeroFactor, zeroMode);
+                sct.setCoeffs(b0, b1, b2, a1, a2);
+                sct.setSaturation(0.0f);
+            }
+
+            double energy = 0.0;
+            for (int n = 0; n < MakeupProbeSamples; ++n)
+            {
+                const double y = probe.process(n == 0 ? 1.0f : 0.0f);
+                energy += y * y;
+            }
+
+            const float limit = juce::Decibels::decibelsToGain(MaxMorphGainDb);
+            return std::clamp(static_cast<float>(1.0 / std::sqrt(std::max(energy, 1.0e-12))), 1.0f / limit, limit);
+        }
+
+        void computePoles(float morph, std::array<PolePair, NumSections>& out) const noexcept
+        {
+            std::array<PolePair, NumSections> base;
+            computeBasePoles(morph, base);
+            applyIntensity(base, morph, out);
+        }
+
+        // Steps 1-2 (interpolation + tune/stretch + remap + inharmonic tuning): everything that does not depend on intensity
+        void computeBasePoles(float morph, std::array<PolePair, NumSections>& out) const noexcept
+        {
+            for (int i = 0; i < NumSections; ++i)
+            {
+                // 1) Interpolate in 48k reference domain (geodesic or linear)
+                // (identical A/B: morph has no effect, use the shape directly)
+                PolePair p48k = bipolar ? interpolatePole(polesCenter[i], morph < 0.5f ? polesA[i] : polesB[i],
+                                                          2.0f * std::abs(morph - 0.5f), geodesicRadius, radiusRule)
+                              : shapesIdentical ? polesA[i]
+                                                : interpolatePole(polesA[i], polesB[i], morph, geodesicRadius, radiusRule);
+                if (morph < 0.0f || morph > 1.0f)
+                {
+                    // Extrapolated trajectory: keep the pole inside the stable upper half-disc
+                    p48k.r     = std::clamp(p48k.r, 0.0f, MAX_POLE_RADIUS);
+                    p48k.theta = std::clamp(p48k.theta, 0.0f, juce::MathConstants<float>::pi);
+                }
+
+                // Global tune (formant shift), kept within [0, pi]
+                if (frequencyShift != 1.0f)
+                    p48k.theta = std::clamp(p48k.theta * frequencyShift, 0.0f, juce::MathConstants<float>::pi);
+
+                out[static_cast<size_t>(i)] = p48k;
+            }
+
+            // Formant stretch around the cluster's mean angle (needs every section, hence the second pass)
+            if (formantStretch != 1.0f)
+            {
+                float mean = 0.0f;
+                for (const auto& p : out) mean += p.theta;
+                mean /= static_cast<float>(NumSections);
+                for (auto& p : out)
+                    p.theta = std::clamp(mean + (p.theta - mean) * formantStretch, 0.0f, juce::MathConstants<float>::pi);
+            }
+
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                // 2) Bilinear remap from 48k to actual sample rate (or keep theta in FrequencyScale mode)
+                PolePair pm = (remapMode == RemapMode::Bilinear) ? remapPole48kToFs(out[i], sr) : out[i];
+
+                // Per-section frequency ratio (inharmonic tuning), kept below Nyquist
+                pm.theta = std::min(pm.theta * inharmonicity[i], 0.999f * juce::MathConstants<float>::pi);
+
+                out[i] = pm;
+            }
+        }
+
+        // Step 3 on top of computeBasePoles output
+        void applyIntensity(const std::array<PolePair, NumSections>& base, float morph,
+                            std::array<PolePair, NumSections>& out) const noexcept
+        {
+            applyIntensity(base, morph, lastIntensity, out);
+        }
+
+        void applyIntensity(const std::array<PolePair, NumSections>& base, float morph, float rawIntensity,
+                            std::array<PolePair, NumSections>& out) const noexcept
+        {
+            const float intensity = std::pow(std::clamp(rawIntensity, 0.0f, 1.0f), intensityCurve);
+            float intensityBoost = 1.0f + intensity * 0.06f; // AUTHENTIC scaling
+            float maxRadius = MAX_POLE_RADIUS;
+
+            // Optional: scale the boost and the hardware clamp by 48k/fs in the log-radius domain
+            // (bandwidth ~ -ln r * fs), so remapped (near z = 1) poles keep the 48k bandwidth instead
+            // of all piling up against MAX_POLE_RADIUS at high rates
+            if (srIntensityComp)
+            {
+                const float ratio = static_cast<float>(REFERENCE_SR / sr);
+                intensityBoost = std::pow(intensityBoost, ratio);
+                maxRadius = std::pow(MAX_POLE_RADIUS, ratio);
+            }
+
+            for (int i = 0; i < NumSections; ++i)
+            {
+                PolePair pm = base[static_cast<size_t>(i)];
+
+                // 3) Apply intensity boost, optional resonance floor and EMU hardware clamp
+                pm.r = std::min(std::max(pm.r * intensityBoost, radiusFloor), maxRadius);
+
+                // Progressive reveal: shrinking the radius toward 0 fades the section to identity
+                if (progressiveReveal)
+                    pm.r *= revealWeight(morph, i);
+
+                out[static_cast<size_t>(i)] = pm;
+            }
+        }
+
+        // Sections fade in one after another as morph rises: 2 active at morph 0, all N at morph 1
+        float revealWeight(float morph, int section) const noexcept
+        {
+            const float minActive = std::min(2.0f, static_cast<float>(NumSections));
+            const float level = minActive + std::clamp(morph, 0.0f, 1.0f) * (NumSections - minActive);
+            return std::clamp(level - static_cast<float>(section), 0.0f, 1.0f);
+        }
+
+        // Zero factor interpolated across the morph (shape A -> shape B)
+        float zeroFactorAt(float morph) const noexcept
+        {
+            return zeroFactorA + std::clamp(morph, 0.0f, 1.0f) * (zeroFactorB - zeroFactorA);
+        }
+
+        // toFs > 0: the biquads are designed at sr as usual, then moved to toFs (oversampled cascades)
+        void applyPoles(const std::array<PolePair, NumSections>& poles, BiquadCascade<NumSections>& cas,
+                        float zeroFactor = 0.9f, double toFs = 0.0) noexcept
+        {
+            for (int i = 0; i < NumSections; ++i)
+            {
+                // Near-origin poles contribute almost nothing: fall back to an identity section
+                auto& sct = cas.sections[static_cast<size_t>(i)];
+                sct.passthrough = poles[static_cast<size_t>(i)].r < passthroughRadius;
+                if (sct.passthrough)
+                {
+                    sct.setCoeffs(1.0f, 0.0f, 0.0f, 0.0f, 0.0f);
+                    sct.rampLeft = 0;
+                    continue;
+                }
+
+                float a1, a2, b0, b1, b2;
+                poleToBiquad(poles[static_cast<size_t>(i)], a1, a2, b0, b1, b2, zeroFactor, zeroMode);
+                if (toFs > 0.0)
+                    biquadToRate(b0, b1, b2, a1, a2, sr, toFs);
+                if (coeffInterval > 0)
+                    sct.rampCoeffsTo(b0, b1, b2, a1, a2, coeffInterval);
+                else
+                    sct.setCoeffs(b0, b1, b2, a1, a2);
+            }
+        }
+
+        // Capture the current cascade coefficients (both channels) into the snapshot
+        void captureSnapshot() noexcept
+        {
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                const auto& l = cascadeL.sections[i];
+                const auto& r = cascadeR.sections[i];
+                snapshotL[i] = { l.b0, l.b1, l.b2, l.a1, l.a2 };
+                snapshotR[i] = { r.b0, r.b1, r.b2, r.a1, r.a2 };
+            }
+            hasSnapshot = true;
+        }
+
+        // Process with the frozen snapshot instead of live morph/shape coefficients
+        void setUseSnapshot(bool on) noexcept
+        {
+            useSnapshot = on && hasSnapshot;
+            if (!useSnapshot)
+            {
+                coeffsDirty = true;
+                return;
+            }
+
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                const auto& l = snapshotL[i];
+                const auto& r = snapshotR[i];
+                cascadeL.sections[i].setCoeffs(l[0], l[1], l[2], l[3], l[4]);
+                cascadeR.sections[i].setCoeffs(r[0], r[1], r[2], r[3], r[4]);
+                cascadeL.sections[i].rampLeft = cascadeR.sections[i].rampLeft = 0;
+                cascadeL.sections[i].passthrough = cascadeR.sections[i].passthrough = false;
+            }
+        }
+
+        // Tuned feedback comb in series after the cascade (string/pluck resonances at multiples of
+        // freqHz). feedback 0 = off; the delay buffer is allocated in prepare() for MinCombHz
+        void setComb(float freqHz, float feedback) noexcept
+        {
+            combHz = std::max(MinCombHz, freqHz);
+            combFeedback = std::clamp(feedback, 0.0f, 0.98f);
+            combDelay = static_cast<float>(sr / combHz);
+        }
+
+        // Per-section all-pass decorrelation: each section is followed by a first-order all-pass,
+        // with the L and R break frequencies spread an equal ratio either side of DecorrelationHz
+        // (0 = off). A stage pair differs in phase by at most 4 atan(sqrt(ratio)) - pi, so the
+        // ratio is chosen to cap the total L/R difference at amount * MaxDecorrelationPhase:
+        // the mono sum never nulls (at most -3 dB at amount 1)
+        void setSectionAllpassDecorrelation(float amount) noexcept
+        {
+            decorrelation = std::clamp(amount, 0.0f, 1.0f);
+            const double pi = juce::MathConstants<double>::pi;
+            const double sqrtRatio = std::tan(0.25 * pi + decorrelation * MaxDecorrelationPhase / (4.0 * NumSections));
+            const double tc = std::tan(pi * std::min(DecorrelationHz, 0.25 * sr) / sr);
+            const auto pole = [](double t) { return static_cast<float>((1.0 - t) / (1.0 + t)); };
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                decorL[i].g = pole(tc / sqrtRatio);
+                decorR[i].g = pole(tc * sqrtRatio);
+            }
+        }
+
+        // Minimum pole radius after interpolation, keeps some resonance with weak shapes (0 = off)
+        void setRadiusFloor(float r) noexcept { radiusFloor = std::clamp(r, 0.0f, MAX_POLE_RADIUS); coeffsDirty = true; }
+
+        // Sections whose pole radius falls below this become passthrough (0 = disabled)
+        void setPassthroughRadius(float r) noexcept { passthroughRadius = std::clamp(r, 0.0f, MAX_POLE_RADIUS); coeffsDirty = true; }
+
+        // Stereo spread: L/R morph offset (0 = both channels share the morph)
+        void setStereoSpread(float s) noexcept { stereoSpread = std::clamp(s, 0.0f, 1.0f); coeffsDirty = true; }
+
+        // Extra morph offset for the right channel only (per-channel modulation routing)
+        void setRightMorphOffset(float o) noexcept { rightMorphOffset = o; }
+
+        // Explicit per-channel morph: recomputes both cascades now (each channel's poles interpolated
+        // separately), bypassing the morph/intensity smoothers. The targets replace the shared morph,
+        // stereo spread and right offset until the next setMorph
+        void updateCoeffsStereo(float morphL, float morphR, float intensity)
+        {
+            stereoMorphTargets = true;
+            stereoMorphL = clampMorph(morphL);
+            stereoMorphR = clampMorph(morphR);
+            morphSmooth.setCurrentAndTargetValue(stereoMorphL);
+            intensitySmooth.setCurrentAndTargetValue(std::clamp(intensity, 0.0f, 1.0f));
+            snapMorphPole();
+            coeffsDirty = true;
+            computeCoeffs();
+        }
+
+        // Delay the earlier channel's wet signal so spread L/R transients line up
+        void setSpreadAlign(bool on) noexcept
+        {
+            spreadAlign = on;
+            alignDelayL = alignDelayR = 0;
+            alignL.reset();
+            alignR.reset();
+            coeffsDirty = true; // the delays are measured in computeCoeffs
+        }
+
+        // Integer arrival-time compensation: compares the energy centroid of each channel's
+        // (linear) impulse response, so broadband transients line up across L/R
+        void updateSpreadAlignment(float zeroFactorL, float zeroFactorR) noexcept
+        {
+            // Probe the same linear path the audio runs (DC blocker, rumble high-pass, zero placement,
+            // passthrough), minus drive and saturation: the centroid is not additive across stages
+            const auto centroid = [this](const std::array<PolePair, NumSections>& poles, float zeroFactor)
+            {
+                DcBlocker dc = dcBlockL;
+                OnePoleHighpass hp = rumbleL;
+                dc.reset();
+                hp.reset();
+
+                BiquadCascade<NumSections> cas;
+                for (size_t i = 0; i < NumSections; ++i)
+                {
+                    auto& s = cas.sections[i];
+                    s.passthrough = poles[i].r < passthroughRadius;
+                    if (s.passthrough) continue;
+                    float a1, a2, b0, b1, b2;
+                    poleToBiquad(poles[i], a1, a2, b0, b1, b2, zeroFactor, zeroMode);
+                    s.setCoeffs(b0, b1, b2, a1, a2);
+                    s.setSaturation(0.0f);
+                }
+
+                double num = 0.0, den = 0.0;
+                for (int n = 0; n < AlignProbeSamples; ++n)
+                {
+                    float x = n == 0 ? 1.0f : 0.0f;
+                    if (dcBlock) x = dc.process(x);
+                    if (rumbleHz > 0.0f) x = hp.process(x);
+                    const double h = cas.process(x);
+                    num += n * h * h;
+                    den += h * h;
+                }
+                return den > 0.0 ? num / den : 0.0;
+            };
+
+            const int diff = static_cast<int>(std::lround(centroid(lastInterpPoles, zeroFactorL) - centroid(lastInterpPolesR, zeroFactorR)));
+            alignDelayL = std::max(0, -diff);
+            alignDelayR = std::max(0,  diff);
+        }
+
+        // Linear DC / Nyquist gain of the current (left-channel) coefficients
+        float dcGain() const noexcept      { return cascadeL.dcGain(); }
+        float nyquistGain() const noexcept { return cascadeL.nyquistGain(); }
+
+        // Phase / group delay (samples) of the current (left-channel) coefficients at freqHz
+        float phaseDelaySamples(float freqHz, float sampleRate) const noexcept
+        {
+            return cascadeL.phaseDelay(juce::MathConstants<float>::twoPi * freqHz / std::max(1.0f, sampleRate));
+        }
+        float groupDelaySamples(float freqHz, float sampleRate) const noexcept
+        {
+            return cascadeL.groupDelay(juce::MathConstants<float>::twoPi * freqHz / std::max(1.0f, sampleRate));
+        }
+
+        // (Hz, dB) pairs of the current (left-channel) response, log-spaced from 20 Hz to just below
+        // Nyquist, for drawing the response curve. Allocates: UI thread only
+        std::vector<std::pair<float, float>> frequencyResponse(float sampleRate, size_t numPoints) const
+        {
+            std::vector<std::pair<float, float>> out;
+            if (numPoints == 0) return out;
+
+            const float fs = std::max(1000.0f, sampleRate);
+            const float lo = 20.0f, hi = 0.499f * fs;
+            out.reserve(numPoints);
+            for (size_t k = 0; k < numPoints; ++k)
+            {
+                const float t = numPoints > 1 ? static_cast<float>(k) / static_cast<float>(numPoints - 1) : 0.0f;
+                const float hz = lo * std::pow(hi / lo, t);
+                out.emplace_back(hz, cascadeL.magnitudeDbAt(hz, fs));
+            }
+            return out;
+        }
+
+        // Linear (left-channel) magnitude at freqs[0..num) scaled so the largest value is exactly 1 (0 dB),
+        // for overlaying responses regardless of absolute gain. No allocation
+        void magnitudeResponseNormalized(float sampleRate, const float* freqs, float* out, size_t num) const noexcept
+        {
+            const float fs = std::max(1000.0f, sampleRate);
+            float peak = 0.0f;
+            for (size_t k = 0; k < num; ++k)
+            {
+                out[k] = cascadeL.magnitudeAt(freqs[k], fs);
+                peak = std::max(peak, out[k]);
+            }
+
+            if (peak <= 0.0f) return;
+            for (size_t k = 0; k < num; ++k)
+                out[k] /= peak;
+        }
+
+        // Linear impulse response of the left cascade (drive and saturation bypassed) for convolution.
+        // Runs on a freshly reset copy; at a different sampleRate the poles are re-derived for that rate.
+        // Allocates: UI/offline use only
+        std::vector<float> linearImpulseResponse(size_t len, float sampleRate) const
+        {
+            auto probe = cascadeL;
+            if (std::abs(sampleRate - sr) > 0.5)
+            {
+                ZPlaneFilterN f = *this;
+                f.sr = std::max(1000.0f, sampleRate);
+                f.coeffInterval = 0; // set coefficients directly, no ramp
+                std::array<PolePair, NumSections> poles{};
+                const float morph = clampMorph(lastMorph - 0.5f * stereoSpread);
+                f.computePoles(morph, poles);
+                f.applyPoles(poles, probe, zeroFactorAt(morph));
+            }
+            probe.reset();
+            for (auto& sct : probe.sections) sct.setSaturation(0.0f);
+
+            std::vector<float> ir(len);
+            for (size_t n = 0; n < len; ++n)
+                ir[n] = probe.process(n == 0 ? 1.0f : 0.0f);
+            return ir;
+        }
+
+        // Time (ms) for the linear impulse response tail to stay below -60 dB of its peak.
+        // Runs on a copy of the left cascade, so live filter state is untouched (UI thread use)
+        float settlingTimeMs(float sampleRate) const noexcept
+        {
+            auto probe = cascadeL;
+            probe.reset();
+            for (auto& s : probe.sections) s.setSaturation(0.0f);
+
+            const int maxSamples = static_cast<int>(std::max(1.0f, sampleRate) * 10.0f); // 10 s cap
+            float peak = 0.0f;
+            int lastAbove = 0;
+            for (int n = 0; n < maxSamples; ++n)
+            {
+                const float y = std::abs(probe.process(n == 0 ? 1.0f : 0.0f));
+                peak = std::max(peak, y);
+                if (y > peak * 1.0e-3f) lastAbove = n;
+                else if (n - lastAbove > 4096) break; // tail has settled
+            }
+            return 1000.0f * static_cast<float>(lastAbove + 1) / std::max(1.0f, sampleRate);
+        }
+
+        // Analytic tail estimate (samples) for host reporting: slowest -60 dB pole decay across both
+        // channels' sections, plus the comb's feedback decay when it is enabled
+        uint32_t tailSamples(float sampleRate) const noexcept
+        {
+            const double fsScale = std::max(1.0f, sampleRate) / std::max(1.0, sr);
+            double longest = 0.0;
+            for (const auto* poles : { &lastInterpPoles, &lastInterpPolesR })
+                for (const auto& p : *poles)
+                    if (p.r > passthroughRadius && p.r > 1.0e-6f)
+                        longest = std::max(longest, std::log(1.0e-3) / std::log(static_cast<double>(p.r)));
+
+            if (combFeedback > 0.0f && pipeline.has(PipelineConfig::Comb))
+                longest += combDelay * std::log(1.0e-3) / std::log(static_cast<double>(combFeedback));
+
+            return static_cast<uint32_t>(std::ceil(longest * fsScale));
+        }
+
+        // Stability sweep over a morph × intensity × drive grid: feeds an impulse at each point
+        // and checks outputs/state stay finite and the tail decays. Runs on copies (UI/CI use)
+        SelfTestReport selfTest(int gridSteps = 5) const
+        {
+            SelfTestReport report;
+            const int steps = std::max(2, gridSteps);
+            constexpr int probeSamples = 16384, tailSamples = 1024;
+
+            for (int mi = 0; mi < steps; ++mi)
+            for (int ii = 0; ii < steps; ++ii)
+            for (int di = 0; di < steps; ++di)
+            {
+                const float span = static_cast<float>(steps - 1);
+                const float m = static_cast<float>(mi) / span, in = static_cast<float>(ii) / span, d = static_cast<float>(di) / span;
+
+                ZPlaneFilterN f = *this;
+                f.reset();
+                f.coeffInterval = 0;
+                f.smoothingSamples = 0;
+                f.morphSmooth.setCurrentAndTargetValue(m);
+                f.intensitySmooth.setCurrentAndTargetValue(in);
+                f.driveSmooth.setCurrentAndTargetValue(d);
+                f.mixSmooth.setCurrentAndTargetValue(1.0f);
+                f.reconfigFade.setCurrentAndTargetValue(1.0f);
+                f.snapMorphPole();
+                f.computeCoeffs();
+
+                bool finite = true;
+                float peak = 0.0f, tailPeak = 0.0f;
+                for (int n = 0; n < probeSamples; ++n)
+                {
+                    float l = (n == 0 ? 1.0f : 0.0f), r = l;
+                    f.process(&l, &r, 1);
+                    finite = finite && std::isfinite(l) && std::isfinite(r)
+                                    && std::isfinite(f.cascadeL.sections[0].z1) && std::isfinite(f.cascadeR.sections[0].z1);
+                    peak = std::max(peak, std::abs(l));
+                    if (n >= probeSamples - tailSamples) tailPeak = std::max(tailPeak, std::abs(l));
+                }
+
+                const bool decays = tailPeak <= std::max(1.0e-6f, peak * 1.0e-3f);
+                ++report.numTested;
+                if (!finite || !decays)
+                {
+                    if (report.numFailed < SelfTestReport::MaxFailures)
+                        report.failures[static_cast<size_t>(report.numFailed)] = { m, in, d, !finite, !decays };
+                    ++report.numFailed;
+                }
+            }
+            return report;
+        }
+
+        // QA metric: largest per-step change (dB) of the log-magnitude response over a morph sweep
+        // of `steps` increments, sampled at 64 log-spaced frequencies (20 Hz .. 0.45 fs). Lower = smoother
+        float morphSmoothness(float sampleRate, int steps) const
+        {
+            constexpr int numFreqs = 64;
+            const int numSteps = std::max(1, steps);
+            const float fs = std::max(1000.0f, sampleRate);
+
+            ZPlaneFilterN f = *this;
+            f.sr = fs;
+
+            std::array<float, numFreqs> w{}, prevDb{}, db{};
+            for (int k = 0; k < numFreqs; ++k)
+            {
+                const float hz = 20.0f * std::pow(0.45f * fs / 20.0f, static_cast<float>(k) / static_cast<float>(numFreqs - 1));
+                w[static_cast<size_t>(k)] = juce::MathConstants<float>::twoPi * hz / fs;
+            }
+
+            const auto responseDb = [&](float morph, std::array<float, numFreqs>& out)
+            {
+                std::array<PolePair, NumSections> poles{};
+                f.computePoles(morph, poles);
+                BiquadCascade<NumSections> cas;
+                for (size_t i = 0; i < NumSections; ++i)
+                    poleToBiquad(poles[i], cas.sections[i].a1, cas.sections[i].a2,
+                                 cas.sections[i].b0, cas.sections[i].b1, cas.sections[i].b2,
+                                 f.zeroFactorAt(morph), f.zeroMode);
+                for (size_t k = 0; k < numFreqs; ++k)
+                    out[k] = 20.0f * std::log10(std::max(1.0e-9f, cas.magnitude(w[k])));
+            };
+
+            float worst = 0.0f;
+            responseDb(0.0f, prevDb);
+            for (int st = 1; st <= numSteps; ++st)
+            {
+                responseDb(static_cast<float>(st) / static_cast<float>(numSteps), db);
+                for (size_t k = 0; k < numFreqs; ++k)
+                    worst = std::max(worst, std::abs(db[k] - prevDb[k]));
+                prevDb = db;
+            }
+            return worst;
+        }
+
+        // Name of the last-loaded shape pair (for UI display). The filter keeps its own copy (truncated to
+        // MaxShapeNameLength), so the caller's string does not need to outlive the call
+        const char* currentShapeName() const noexcept { return shapeName.data(); }
+
+        // Get last interpolated poles (for UI visualization)
+        const std::array<PolePair, NumSections>& getLastPoles() const noexcept { return lastInterpPoles; }
+        const std::array<PolePair, NumSections>& getLastPolesRight() const noexcept { return lastInterpPolesR; }
+
+        // Live coefficients of section `index` on channel 0 (left) or 1 (right), for debugging / design checks.
+        // Mid-ramp values when coefficient ramping is active; passthrough sections report the identity
+        BiquadCoeffs sectionCoeffs(int channel, size_t index) const noexcept
+        {
+            const auto& sct = (channel == 0 ? cascadeL : cascadeR).sections[std::min(index, N - 1)];
+            if (sct.passthrough) return { 1.0f, 0.0f, 0.0f, 0.0f, 0.0f };
+            return { sct.b0, sct.b1, sct.b2, sct.a1, sct.a2 };
+        }
+
+        // Section `index` (left channel) as (cutoff normalized to sampleRate's Nyquist, Q), the inverse of
+        // poleFromFreqQ. Poles are at the prepared rate; another sampleRate re-expresses the same Hz
+        std::pair<float, float> sectionParams(size_t index, float sampleRate) const noexcept
+        {
+            const auto& p = lastInterpPoles[std::min(index, N - 1)];
+            const float hz = p.theta * static_cast<float>(sr) / 6.283185307f;
+            const float cutoff = std::clamp(hz / (0.5f * std::max(1.0f, sampleRate)), 0.0f, 1.0f);
+            const float lnR = std::log(std::clamp(p.r, 1.0e-6f, 0.999999f));
+            return { cutoff, p.theta / (-2.0f * lnR) };
+        }
+
+        // Largest pole radius across both channels (1.0 = unity circle; MAX_POLE_RADIUS is the hard cap)
+        float maxRadius() const noexcept
+        {
+            float r = 0.0f;
+            for (size_t i = 0; i < NumSections; ++i)
+                r = std::max({ r, lastInterpPoles[i].r, lastInterpPolesR[i].r });
+            return r;
+        }
+
+        // Full-system poles of the order-2N (left-channel) filter: a cascade's poles are the union of
+        // its sections' poles, so these are the N upper-half-plane pairs (conjugates mirror them)
+        std::array<PolePair, NumSections> systemPoles() const noexcept { return lastInterpPoles; }
+
+        // All 2N poles as complex values, ordered p0, conj(p0), p1, conj(p1), ...
+        std::array<std::complex<float>, 2 * NumSections> systemPolesComplex() const noexcept
+        {
+            std::array<std::complex<float>, 2 * NumSections> out{};
+            for (size_t i = 0; i < NumSections; ++i)
+            {
+                out[2 * i]     = std::polar(lastInterpPoles[i].r, lastInterpPoles[i].theta);
+                out[2 * i + 1] = std::conj(out[2 * i]);
+            }
+            return out;
+        }
+
+        // Sample-accurate morph: morphBuf supplies one morph value per sample. Coefficients are
+        // recomputed every CoeffGridSamples from the buffer (bypassing the morph smoother);
+        // intensity/drive/mix are set as smoothed targets. Replaces updateCoeffsBlock + process.
+        void processStereoModulated(float* left, float* right, int num, const float* morphBuf,
+                                    float intensity, float drive, float mix)
+        {
+            releaseStereoMorphTargets();
+            setIntensity(intensity);
+            setDrive(drive);
+            setMix(mix);
+
+            for (int start = 0; start < num; start += CoeffGridSamples)
+            {
+                const int len = std::min(CoeffGridSamples, num - start);
+                morphSmooth.setCurrentAndTargetValue(clampMorph(morphBuf[start]));
+                if (smoothingSamples == 0)
+                {
+                    intensitySmooth.skip(len);
+                    snapMorphPole();
+                    computeCoeffs();
+                }
+                process(left + start, right + start, len);
+            }
+        }
+
+        // Audio-rate morph/intensity: the full pole math (interpolation, remap, biquad design for both
+        // channels) runs for every sample from morphBuf/intensityBuf, with no coefficient ramp.
+        // Expensive (~2-3k cycles/sample measured on x86-64, vs a few for block-rate updates): opt-in only.
+        // drive/mix are set as smoothed targets. Replaces updateCoeffsBlock + process.
+        void processStereoModulatedPerSample(float* left, float* right, int num, const float* morphBuf,
+                                             const float* intensityBuf, float drive, float mix)
+        {
+            setDrive(drive);
+            setMix(mix);
+
+            const int savedInterval = coeffInterval;
+            coeffInterval = 0;
+            for (int n = 0; n < num; ++n)
+            {
+                morphSmooth.setCurrentAndTargetValue(clampMorph(morphBuf[n]));
+                intensitySmooth.setCurrentAndTargetValue(std::clamp(intensityBuf[n], 0.0f, 1.0f));
+                snapMorphPole();
+                computeCoeffs();
+                process(left + n, right + n, 1);
+            }
+            coeffInterval = savedInterval;
+        }
+
+        // Process block (stereo)
+        void process(float* left, float* right, int num) { processImpl<false>(left, right, num); }
+
+        // Host-supplied automation: drive and mix ramp linearly from *Start to *End across the block (stepped
+        // before every sample: the first is one step in, the last lands on *End), replacing the internal
+        // 10/20 ms smoothing for this block only. Use equal start/end values for a fixed setting; the next
+        // setDrive/setMix smooths from *End as usual
+        void processStereoRamped(float* left, float* right, int num,
+                                 float driveStart, float driveEnd, float mixStart, float mixEnd)
+        {
+            if (num <= 0) return;
+
+            driveSmooth.reset(num);
+            mixSmooth.reset(num);
+            driveSmooth.setCurrentAndTargetValue(std::clamp(driveStart, 0.0f, 1.0f));
+            mixSmooth.setCurrentAndTargetValue(std::clamp(mixStart, 0.0f, 1.0f));
+            setDrive(driveEnd);
+            setMix(mixEnd);
+
+            process(left, right, num);
+
+            // Back to the regular ramp length (snaps to the *End targets, which the ramp has reached)
+            driveSmooth.reset(sr, 0.01);
+            mixSmooth.reset(sr, 0.02);
+        }
+
+        // 100% wet fast path (EFFECT mode): no dry capture or equal-power mix; matches process() at mix = 1.
+        // The mix smoother is left where it is, so only switch here once isFullyWet() is true
+        void processWetOnly(float* left, float* right, int num) { processImpl<true>(left, right, num); }
+
+        bool isFullyWet() const noexcept { return !mixSmooth.isSmoothing() && mixSmooth.getTargetValue() >= 1.0f; }
+
+        template <bool WetOnly>
+        void processImpl(float* left, float* right, int num)
+        {
+            const bool oversample = oversamplingActive();
+            if (oversample)
+                ++oversampledBlocks;
+            const int osLatency = osL.latencySamples();
+            const bool dcOn = dcBlock && pipeline.has(PipelineConfig::InputHighPass);
+            const bool outGainOn = pipeline.has(PipelineConfig::OutputGain);
+
+            for (int n = 0; n < num; ++n)
+            {
+                if (smoothingSamples > 0)
+                {
+                    // Step first, so a grid point's coefficients match this sample's morph (not the
+                    // previous one). Fixed sample grid (persists across blocks) for block-size independence
+                    morphSmooth.getNextValue();
+                    intensitySmooth.getNextValue();
+
+                    if (gridCounter == 0)
+                        computeCoeffs();
+                    gridCounter = (gridCounter + 1) % (coeffInterval > 0 ? coeffInterval : CoeffGridSamples);
+                }
+                else if (subBlockLen > 0)
+                {
+                    if (subBlockCounter == 0)
+                    {
+                        morphSmooth.skip(subBlockLen);
+                        intensitySmooth.skip(subBlockLen);
+                        computeCoeffs();
+                    }
+                    subBlockCounter = (subBlockCounter + 1) % subBlockLen;
+                }
+
+                if (coeffInterval > 0)
+                {
+                    cascadeL.advanceRamp();
+                    cascadeR.advanceRamp();
+                    cascadeOsL.advanceRamp();
+                    cascadeOsR.advanceRamp();
+                }
+
+                // Advance smoothers per-sample for proper 20ms ramps
+                const float drive = driveSmooth.getNextValue();
+                const float mix   = WetOnly ? 1.0f : mixSmooth.getNextValue();
+
+                const float driveGain = 1.0f + drive * 4.0f;
+
+                // Input trim; the dry reference is either the raw input or the trimmed input
+                const float trim = inputGainSmooth.getNextValue();
+                const float inGain = pipeline.has(PipelineConfig::InputGain) ? trim : 1.0f;
+                // With oversampling configured everything but the oversampled wet input is delayed by
+                // its latency, so the output timing does not change when the path is bypassed
+                const float srcL = left[n];
+                const float srcR = right[n];
+                const float rawL = osLatency > 0 ? osDelayL.process(srcL, osLatency) : srcL;
+                const float rawR = osLatency > 0 ? osDelayR.process(srcR, osLatency) : srcR;
+                const float inL = rawL * inGain;
+                const float inR = rawR * inGain;
+                float dryL = (dryTap == DryTap::RawInput) ? rawL : inL;
+                float dryR = (dryTap == DryTap::RawInput) ? rawR : inR;
+                float wetG = 1.0f, dryG = 0.0f;
+                if constexpr (!WetOnly)
+                {
+                    // Mix (equal-power to avoid perceived dips around 50% and preserve tone with nonlinearities)
+                    // Use TRUE dry signal (dryL/dryR) not driven signal for authentic bypass tone
+                    // (the sqrt pair is only recomputed when the smoothed mix actually moves)
+                    if (mix != mixGainMix)
+                    {
+                        mixGains(mix, mixEndpointSmoothing, mixGainWet, mixGainDry);
+                        mixGainMix = mix;
+                    }
+                    wetG = mixGainWet;
+                    dryG = mixGainDry;
+                    if (!pipeline.has(PipelineConfig::Mix)) { wetG = 1.0f; dryG = 0.0f; }
+                }
+                wetG *= reconfigFade.getNextValue();
+
+                if (msMode == MsMode::MidOnly || msMode == MsMode::SideOnly)
+                {
+                    // M/S encode, filter only the selected component (left cascade), decode. The other
+                    // component passes through from the dry tap
+                    float mid  = 0.5f * (dryL + dryR);
+                    float side = 0.5f * (dryL - dryR);
+                    const bool filterMid = (msMode == MsMode::MidOnly);
+                    float& sel = filterMid ? mid : side;
+                    const float selIn = 0.5f * (filterMid ? (inL + inR) : (inL - inR));
+
+                    float wet = filteredDrive(dcOn ? dcBlockL.process(selIn) : selIn, rumbleL, driveGain, mix);
+                    if (pipeline.has(PipelineConfig::Cascade))
+                        wet = cascadeL.process(wet);
+                    if (combFeedback > 0.0f && pipeline.has(PipelineConfig::Comb))
+                        wet = combL.process(wet, combDelay, combFeedback);
+                    const float gain = morphGainL.getNextValue();
+                    morphGainR.getNextValue();
+                    if (outGainOn) wet *= gain;
+                    sel = wet * wetG + sel * dryG;
+
+                    left[n]  = mid + side;
+                    right[n] = mid - side;
+                    if (mixbusClip > 0.0f)
+                        limitMixbus(left[n], right[n]);
+                    continue;
+                }
+
+                // MidSide: the left channel chain carries mid, the right carries side
+                const bool midSide = (msMode == MsMode::MidSide);
+                const auto encode = [midSide](float& a, float& b)
+                {
+                    if (!midSide) return;
+                    const float m = 0.5f * (a + b);
+                    b = 0.5f * (a - b);
+                    a = m;
+                };
+
+                // Pre-drive (authentic: tanh on input) + cascade, optionally oversampled
+                float wetL, wetR;
+                if (oversample)
+                {
+                    float osInL = srcL * inGain, osInR = srcR * inGain;
+                    encode(osInL, osInR);
+                    if (dcOn)
+                    {
+                        osInL = dcBlockL.process(osInL);
+                        osInR = dcBlockR.process(osInR);
+                    }
+                    if (osMode == OversamplingMode::Crossover)
+                    {
+                        wetL = crossoverDrive(osInL, osL, xoverL, xoverDelayL, rumbleL, driveGain, mix);
+                        wetR = crossoverDrive(osInR, osR, xoverR, xoverDelayR, rumbleR, driveGain, mix);
+                        if (pipeline.has(PipelineConfig::Cascade))
+                        {
+                            wetL = cascadeL.process(wetL);
+                            wetR = cascadeR.process(wetR);
+                        }
+                    }
+                    else
+                    {
+                        wetL = processOversampled(osInL, osL, cascadeOsL, rumbleL, driveGain, mix);
+                        wetR = processOversampled(osInR, osR, cascadeOsR, rumbleR, driveGain, mix);
+                    }
+                }
+                else
+                {
+                    float fl = inL, fr = inR;
+                    encode(fl, fr);
+                    const float l = filteredDrive(dcOn ? dcBlockL.process(fl) : fl, rumbleL, driveGain, mix);
+                    const float r = filteredDrive(dcOn ? dcBlockR.process(fr) : fr, rumbleR, driveGain, mix);
+
+                    if (!pipeline.has(PipelineConfig::Cascade))
+                    {
+                        // Stage disabled: driven input passes straight through
+                        wetL = l;
+                        wetR = r;
+                    }
+                    else if (decorrelation > 0.0f)
+                    {
+                        wetL = processDecorrelated(cascadeL, decorL, l);
+                        wetR = processDecorrelated(cascadeR, decorR, r);
+                    }
+                    else
+                    {
+                        wetL = cascadeL.process(l);
+                        wetR = cascadeR.process(r);
+                    }
+                }
+
+                if (midSide)
+                {
+                    const float m = wetL;
+                    wetL = m + wetR;
+                    wetR = m - wetR;
+                }
+
+                if (spreadAlign)
+                {
+                    wetL = alignL.process(wetL, alignDelayL);
+                    wetR = alignR.process(wetR, alignDelayR);
+                }
+
+                if (combFeedback > 0.0f && pipeline.has(PipelineConfig::Comb))
+                {
+                    wetL = combL.process(wetL, combDelay, combFeedback);
+                    wetR = combR.process(wetR, combDelay, combFeedback);
+                }
+
+                const float gainL = morphGainL.getNextValue();
+                const float gainR = morphGainR.getNextValue();
+                if (outGainOn)
+                {
+                    wetL *= gainL;
+                    wetR *= gainR;
+                }
+
+                if (linearPhase)
+                    linearPhaseStep(wetL, wetR, dryL, dryR);
+
+                left[n]  = wetL * wetG + dryL * dryG;
+                right[n] = wetR * wetG + dryR * dryG;
+                if (mixbusClip > 0.0f)
+                    limitMixbus(left[n], right[n]);
+            }
+
+            drainNonFinite();
+        }
+
+        // Non-finite section outputs (zeroed) since construction: rising means the coefficients are blowing up.
+        // Any thread; updated once per processed block
+        uint64_t nanEvents() const noexcept { return nanEventCount.load(); }
+
+        // Sum the per-section counters into the shared atomic (only touches it when something happened)
+        void drainNonFinite() noexcept
+        {
+            uint32_t events = 0;
+            for (auto* c : { &cascadeL, &cascadeR, &cascadeOsL, &cascadeOsR })
+                for (auto& sct : c->sections)
+                {
+                    events += sct.nonFiniteCount;
+                    sct.nonFiniteCount = 0;
+                }
+            if (events > 0)
+                nanEventCount.add(events);
+        }
+
+        // Interleaved [L, R, L, R, ...] buffer of numValues floats (must be even); deinterleaved in
+        // fixed-size chunks so no allocation happens on the audio thread
+        void processInterleaved(float* buf, int numValues)
+        {
+            jassert(numValues % 2 == 0);
+            const int frames = numValues / 2;
+
+            std::array<float, ScratchChunk> l{}, r{};
+            for (int start = 0; start < frames; start += ScratchChunk)
+            {
+                const int n = std::min(ScratchChunk, frames - start);
+                float* frame = buf + 2 * start;
+                for (int i = 0; i < n; ++i)
+                {
+                    l[static_cast<size_t>(i)] = frame[2 * i];
+                    r[static_cast<size_t>(i)] = frame[2 * i + 1];
+                }
+
+                process(l.data(), r.data(), n);
+
+                for (int i = 0; i < n; ++i)
+                {
+                    frame[2 * i]     = l[static_cast<size_t>(i)];
+                    frame[2 * i + 1] = r[static_cast<size_t>(i)];
+                }
+            }
+        }
+
+        // A/B morph between two independently configured filters: both process the input (each with
+        // its own drive/mix settings) and their outputs are equal-power blended by t (0 = this, 1 = other).
+        // Both always run so neither filter's state jumps when t moves.
+        void crossfade(ZPlaneFilterN& other, float* left, float* right, int num, float t)
+        {
+            const float a = std::clamp(t, 0.0f, 1.0f) * juce::MathConstants<float>::halfPi;
+            const float gA = std::cos(a), gB = std::sin(a);
+
+            std::array<float, ScratchChunk> bl{}, br{};
+            for (int start = 0; start < num; start += ScratchChunk)
+            {
+                const int n = std::min(ScratchChunk, num - start);
+                float* l = left + start;
+                float* r = right + start;
+                std::copy(l, l + n, bl.begin());
+                std::copy(r, r + n, br.begin());
+
+                process(l, r, n);
+                other.process(bl.data(), br.data(), n);
+
+                for (int i = 0; i < n; ++i)
+                {
+                    l[i] = gA * l[i] + gB * bl[static_cast<size_t>(i)];
+                    r[i] = gA * r[i] + gB * br[static_cast<size_t>(i)];
+                }
+            }
+        }
+
+        void resetMorphIntensitySmoothers()
+        {
+            if (smoothingSamples > 0)
+            {
+                morphSmooth.reset(smoothingSamples);
+                intensitySmooth.reset(smoothingSamples);
+            }
+            else
+            {
+                morphSmooth.reset(sr, 0.02);
+                intensitySmooth.reset(sr, 0.02);
+            }
+        }
+
+        void advanceStateGlide(int numSamples) noexcept
+        {
+            glideElapsed = std::min(glideTotal, glideElapsed + std::max(0, numSamples));
+            const float t = static_cast<float>(glideElapsed) / static_cast<float>(glideTotal);
+            const auto lerp = [t](float a, float b) { return a + t * (b - a); };
+
+            morphSmooth.setCurrentAndTargetValue(clampMorph(lerp(glideFrom.morph, glideTo.morph)));
+            intensitySmooth.setCurrentAndTargetValue(std::clamp(lerp(glideFrom.intensity, glideTo.intensity), 0.0f, 1.0f));
+            driveSmooth.setCurrentAndTargetValue(std::clamp(lerp(glideFrom.drive, glideTo.drive), 0.0f, 1.0f));
+            setSectionSaturation(lerp(glideFrom.saturation, glideTo.saturation));
+            snapMorphPole(); // the glide is the smoothing: the morph one-pole must not lag behind it
+        }
+
+        // Authentic tanh pre-drive; with drive-follows-mix the stage fades to linear as mix -> 0
+        inline float preDrive(float x, float driveGain, float mix) const noexcept
+        {
+            if (!pipeline.has(PipelineConfig::Drive)) return x;
+            const float driven = std::tanh(x * driveGain);
+            return driveFollowsMix ? x + mix * (driven - x) : driven;
+        }
+
+        // Push this sample's forward wet/dry, return the zero-phase wet and dry from 2 frames ago
+        inline void linearPhaseStep(float& wetL, float& wetR, float& dryL, float& dryR) noexcept
+        {
+            if (lpFwdL.empty()) return; // not prepared
+
+            const size_t w = static_cast<size_t>(lpWrite), p = static_cast<size_t>(lpPos);
+            const float outWL = lpOutL[p], outWR = lpOutR[p];
+            const float outDL = lpDryL[w], outDR = lpDryR[w];
+            lpFwdL[w] = wetL; lpFwdR[w] = wetR;
+            lpDryL[w] = dryL; lpDryR[w] = dryR;
+            wetL = outWL; wetR = outWR;
+            dryL = outDL; dryR = outDR;
+
+            lpWrite = (lpWrite + 1) % (2 * LinearPhaseFrame);
+            if (++lpPos == LinearPhaseFrame)
+            {
+                lpPos = 0;
+                runBackwardPass(cascadeL, lpFwdL, lpOutL);
+                runBackwardPass(cascadeR, lpFwdR, lpOutR);
+            }
+        }
+
+        // Backward pass over the last 2 frames (newest first): the newer frame is warm-up only,
+        // the older one becomes the next output frame
+        void runBackwardPass(const BiquadCascade<NumSections>& forward, const std::vector<float>& fwd, std::vector<float>& out) noexcept
+        {
+            auto back = forward;
+            back.reset();
+            const int ring = 2 * LinearPhaseFrame;
+            for (int k = 1; k <= ring; ++k)
+            {
+                const float y = back.process(fwd[static_cast<size_t>((lpWrite - k + ring) % ring)]);
+                if (k > LinearPhaseFrame)
+                    out[static_cast<size_t>(ring - k)] = y;
+            }
+        }
+
+        inline void limitMixbus(float& l, float& r) noexcept
+        {
+            if (limiterAttackMs > 0.0f || limiterReleaseMs > 0.0f)
+            {
+                const float peak = std::max(std::abs(l), std::abs(r));
+                const float c = peak > limiterEnv ? limiterAttackCoef : limiterReleaseCoef;
+                limiterEnv = peak + c * (limiterEnv - peak);
+                const float gain = limiterEnv > mixbusKnee ? mixbusKnee / limiterEnv : 1.0f;
+                l *= gain;
+                r *= gain;
+            }
+            l = softKneeClip(l, mixbusKnee);
+            r = softKneeClip(r, mixbusKnee);
+        }
+
+        // Pre-drive with the optional rumble high-pass on the selected side of it
+        inline float filteredDrive(float x, OnePoleHighpass& hp, float driveGain, float mix) noexcept
+        {
+            const bool hpOn = rumbleHz > 0.0f && pipeline.has(PipelineConfig::InputHighPass);
+            if (hpOn && hpPosition == HpPosition::PreDrive) x = hp.process(x);
+            x = preDrive(x, driveGain, mix);
+            if (hpOn && hpPosition == HpPosition::PostDrive) x = hp.process(x);
+            return x;
+        }
+
+        // Base-rate rumble high-pass, then drive + cascade at the oversampled rate. A post-drive
+        // high-pass moves after the cascade here (both linear, so the response is unchanged)
+        inline float processOversampled(float x, Oversampler& os, BiquadCascade<NumSections>& cas,
+                                        OnePoleHighpass& hp, float driveGain, float mix) noexcept
+        {
+            const bool hpOn = rumbleHz > 0.0f && pipeline.has(PipelineConfig::InputHighPass);
+            if (hpOn && hpPosition == HpPosition::PreDrive) x = hp.process(x);
+
+            std::array<float, Oversampler::MaxFactor> buf{};
+            os.up(x, buf.data());
+            const bool cascadeOn = pipeline.has(PipelineConfig::Cascade);
+            for (int k = 0; k < os.factor(); ++k)
+            {
+                float& v = buf[static_cast<size_t>(k)];
+                v = preDrive(v, driveGain, mix);
+                if (cascadeOn) v = cas.process(v);
+            }
+            oversampledOps += static_cast<uint64_t>(os.factor() * (cascadeOn ? 1 + NumSections : 1));
+            x = os.down(buf.data());
+
+            if (hpOn && hpPosition == HpPosition::PostDrive) x = hp.process(x);
+            return x;
+        }
+
+        // Crossover sub-mode drive: low band at the base rate, high band oversampled. Returns the driven
+        // signal for the base-rate cascade (rumble high-pass handled as in filteredDrive)
+        inline float crossoverDrive(float x, Oversampler& os, CrossoverLR4& xo, IntegerDelay<64>& lowDelay,
+                                    OnePoleHighpass& hp, float driveGain, float mix) noexcept
+        {
+            const bool hpOn = rumbleHz > 0.0f && pipeline.has(PipelineConfig::InputHighPass);
+            if (hpOn && hpPosition == HpPosition::PreDrive) x = hp.process(x);
+
+            float lo, hi;
+            xo.split(x, lo, hi);
+            lo = lowDelay.process(preDrive(lo, driveGain, mix), os.latencySamples());
+
+            std::array<float, Oversampler::MaxFactor> buf{};
+            os.up(hi, buf.data());
+            for (int k = 0; k < os.factor(); ++k)
+                buf[static_cast<size_t>(k)] = preDrive(buf[static_cast<size_t>(k)], driveGain, mix);
+            oversampledOps += static_cast<uint64_t>(os.factor());
+            x = lo + os.down(buf.data());
+
+            if (hpOn && hpPosition == HpPosition::PostDrive) x = hp.process(x);
+            return x;
+        }
+
+        // Oversampled path for this block: configured, supported by the current mode, and there is
+        // enough drive or saturation to alias (see setOversamplingBypassThreshold)
+        bool oversamplingActive() const noexcept
+        {
+            if (osL.factor() == 1 || msMode == MsMode::MidOnly || msMode == MsMode::SideOnly
+                || decorrelation > 0.0f || useSnapshot)
+                return false;
+
+            const bool driveOn = pipeline.has(PipelineConfig::Drive)
+                              && std::max(driveSmooth.getCurrentValue(), driveSmooth.getTargetValue()) > osBypassThreshold;
+            const bool satOn = pipeline.has(PipelineConfig::Saturation) && saturation > osBypassThreshold;
+            return driveOn || satOn;
+        }
+
+        void resetOversampling() noexcept
+        {
+            osL.reset(); osR.reset();
+            cascadeOsL.reset(); cascadeOsR.reset();
+            osDelayL.reset(); osDelayR.reset();
+            xoverL.reset(); xoverR.reset();
+            xoverDelayL.reset(); xoverDelayR.reset();
+        }
+
+        void setShapeName(const char* name) noexcept
+        {
+            if (name == nullptr) name = "Custom";
+            size_t i = 0;
+            for (; i < MaxShapeNameLength && name[i] != '\0'; ++i)
+                shapeName[i] = name[i];
+            shapeName[i] = '\0';
+        }
+
+        void resetLinearPhase() noexcept
+        {
+            for (auto* v : { &lpFwdL, &lpFwdR, &lpDryL, &lpDryR, &lpOutL, &lpOutR })
+                std::fill(v->begin(), v->end(), 0.0f);
+            lpWrite = lpPos = 0;
+        }
+
+        void resetDecorrelation() noexcept
+        {
+            for (auto& ap : decorL) ap.reset();
+            for (auto& ap : decorR) ap.reset();
+        }
+
+        static inline float processDecorrelated(BiquadCascade<NumSections>& cas,
+                                                std::array<FirstOrderAllpass, NumSections>& ap, float x) noexcept
+        {
+            for (size_t i = 0; i < NumSections; ++i)
+                x = ap[i].process(cas.sections[i].process(x));
+            return x;
+        }
+
+        static constexpr int CoeffGridSamples = 32;
+        static constexpr float MaxStaleDelta = 0.005f;
+        static constexpr float MinCombHz = 20.0f;
+        static constexpr double MaxDecorrelationPhase = 0.5 * juce::MathConstants<double>::pi;
+        static constexpr double DecorrelationHz = 1000.0;
+        static constexpr int AlignProbeSamples = 1024;
+        static constexpr int ScratchChunk = 256;
+        static constexpr int MakeupProbeSamples = 2048;
+        static constexpr float MaxMorphGainDb = 36.0f;
+        static constexpr int LinearPhaseFrame = 4096;
+        static constexpr double DcBlockHz = 10.0; // R ~ 0.9987 at 48k, well below the SUB resonances
+
+        static std::array<float, NumSections> unityRatios() noexcept
+        {
+            std::array<float, NumSections> r{};
+            r.fill(1.0f);
+            return r;
+        }
+
+        double sr { REFERENCE_SR };
         BiquadCascade<NumSections> cascadeL, cascadeR;
         std::array<PolePair, NumSections> polesA{}, polesB{};
-        std::array<PolePair, NumSections> lastInterpPoles{};
-        std::array<float,12> shapeA{}, shapeB{};
+        std::array<PolePair, NumSections> polesCenter{}; // bipolar mode (polesA = left, polesB = right)
+        bool bipolar{false};
+        std::array<PolePair, NumSections> lastInterpPoles{}, lastInterpPolesR{};
+        std::array<PolePair, NumSections> basePolesL{}, basePolesR{}; // pre-intensity (see computeBasePoles)
+        bool intensityFastPath{true};
+        Oversampler osL, osR;
+        BiquadCascade<NumSections> cascadeOsL, cascadeOsR;     // run at sr x oversampling factor
+        IntegerDelay<64> osDelayL, osDelayR;                  // dry / bypassed-path latency match
+        float osBypassThreshold{1.0e-3f};
+        int oversampledBlocks{0};
+        uint64_t oversampledOps{0}; // stage evaluations (drive, cascade sections) run at the oversampled rate
+        OversamplingMode osMode{OversamplingMode::FullBand};
+        float xoverHz{4000.0f};
+        CrossoverLR4 xoverL, xoverR;
+        IntegerDelay<64> xoverDelayL, xoverDelayR;            // crossover low band, aligned with the high band
+        EventCounter nanEventCount; // see nanEvents
+        float mixGainMix{-1.0f}, mixGainWet{1.0f}, mixGainDry{0.0f}; // equal-power gains for the last mix value
+        int intensityOnlyUpdates{0};
+        ShapeArray shapeA{}, shapeB{};
+        bool shapesIdentical{true};
+        bool progressiveReveal{false};
+        static constexpr size_t MaxShapeNameLength = 31;
+        std::array<char, MaxShapeNameLength + 1> shapeName{ 'C', 'u', 's', 't', 'o', 'm', '\0' };
         float lastMorph{0.5f}, lastIntensity{AUTHENTIC_INTENSITY};
+        float morphSmoothingMs{0.0f};
+        float morphPole{0.5f}, intensityPole{AUTHENTIC_INTENSITY};
+        bool geodesicRadius{GEODESIC_RADIUS};
+        RemapMode remapMode{RemapMode::Bilinear};
+        bool srIntensityComp{false};
+        float intensityCurve{1.0f};
+        float zeroFactorA{0.9f}, zeroFactorB{0.9f};
+        ZeroMode zeroMode{ZeroMode::Bandpass};
+        bool linearPhase{false};
+        std::vector<float> lpFwdL, lpFwdR, lpDryL, lpDryR, lpOutL, lpOutR;
+        int lpWrite{0}, lpPos{0};
+        float mixbusClip{0.0f}, mixbusKnee{1.0f};
+        float limiterAttackMs{0.0f}, limiterReleaseMs{0.0f};
+        float limiterAttackCoef{0.0f}, limiterReleaseCoef{0.0f}, limiterEnv{0.0f};
+        bool dcBlock{false};
+        DcBlocker dcBlockL, dcBlockR;
+        uint64_t seed{ nextInstanceSeed() };
+        NoiseSource noise{ seed }; // declared after seed
+        float rumbleHz{0.0f};
+        HpPosition hpPosition{HpPosition::PreDrive};
+        OnePoleHighpass rumbleL, rumbleR;
+        std::array<float, NumSections> inharmonicity{ unityRatios() };
+        float frequencyShift{1.0f}; // pole angle ratio (setFrequencyShift)
+        float formantStretch{1.0f}; // spacing factor around the mean angle (setFormantStretch)
+        MorphClamp morphClamp{MorphClamp::Clamp};
+        RadiusRule radiusRule{RadiusRule::Blend};
+        float mixEndpointSmoothing{0.0f};
+        bool driveFollowsMix{false};
+        MsMode msMode{MsMode::Stereo};
+        DryTap dryTap{DryTap::PostInputGain};
+        ReconfigPolicy reconfigPolicy{ReconfigPolicy::Reset};
+        PipelineConfig pipeline{};
+        int smoothingSamples{0};
+        int gridCounter{0};
+        int coeffInterval{0};
+        float coeffQuality{0.0f};
+        int subBlockLen{0}, subBlockCounter{0};
+        float saturation{AUTHENTIC_SATURATION};
+        FilterState glideFrom{}, glideTo{};
+        int glideTotal{0}, glideElapsed{0};
+        float radiusFloor{0.0f};
+        float passthroughRadius{0.0f};
+        float stereoSpread{0.0f};
+        float rightMorphOffset{0.0f};
+        float lastRightMorphOffset{0.0f};
+        bool stereoMorphTargets{false};
+        float stereoMorphL{0.5f}, stereoMorphR{0.5f};
+        float skipThreshold{0.0f};
+        float prevBlockMorph{0.5f}, prevBlockIntensity{AUTHENTIC_INTENSITY};
+        bool coeffsDirty{true};
+        int skippedCoeffUpdates{0};
+        bool spreadAlign{false};
+        int alignDelayL{0}, alignDelayR{0};
+        IntegerDelay<1024> alignL, alignR;
+        int samplesSinceCoeffUpdate{0};
+        std::array<std::array<float, 5>, NumSections> snapshotL{}, snapshotR{}; // b0 b1 b2 a1 a2
+        bool hasSnapshot{false}, useSnapshot{false};
+        float combHz{220.0f}, combFeedback{0.0f}, combDelay{0.0f};
+        FeedbackComb combL, combR;
+        float decorrelation{0.0f};
+        std::array<FirstOrderAllpass, NumSections> decorL{}, decorR{};
         juce::LinearSmoothedValue<float> morphSmooth, driveSmooth, intensitySmooth, mixSmooth;
+        juce::LinearSmoothedValue<float> reconfigFade{1.0f};
+        juce::LinearSmoothedValue<float> inputGainSmooth{1.0f};
+        juce::LinearSmoothedValue<float> morphGainL{1.0f}, morphGainR{1.0f};
+        bool morphGainComp{false};
+        MakeupTable makeupTableDb{};
+        std::array<MakeupTable, SHAPE_PAIRS.size()> pairMakeupTableDb{};
+    };
+
+    using ZPlaneFilter  = ZPlaneFilterN<6>;
+    using ZPlaneFilter6 = ZPlaneFilter;
+
+    // Chainable configuration for library users; build() returns a prepared filter
+    // configured through the same setters as imperative setup
+    struct ZPlaneFilterBuilder
+    {
+        ZPlaneFilterBuilder& shapes(const std::array<float,12>& a, const std::array<float,12>& b) noexcept { shapeA = a; shapeB = b; return *this; }
+        ZPlaneFilterBuilder& sampleRate(double fs) noexcept    { sr = fs; return *this; }
+        ZPlaneFilterBuilder& blockSize(int n) noexcept         { block = n; return *this; }
+        ZPlaneFilterBuilder& morph(float m) noexcept           { morphValue = m; return *this; }
+        ZPlaneFilterBuilder& intensity(float i) noexcept       { intensityValue = i; return *this; }
+        ZPlaneFilterBuilder& drive(float d) noexcept           { driveValue = d; return *this; }
+        ZPlaneFilterBuilder& saturation(float s) noexcept      { saturationValue = s; return *this; }
+        ZPlaneFilterBuilder& mix(float m) noexcept             { mixValue = m; return *this; }
+        ZPlaneFilterBuilder& geodesic(bool on) noexcept        { geodesicValue = on; return *this; }
+
+        ZPlaneFilter build() const
+        {
+            ZPlaneFilter f;
+            f.prepare(sr, block);
+            f.setShapePair(shapeA, shapeB);
+            f.setGeodesicRadius(geodesicValue);
+            f.setMorph(morphValue);
+            f.setIntensity(intensityValue);
+            f.setDrive(driveValue);
+            f.setSectionSaturation(saturationValue);
+            f.setMix(mixValue);
+
+            // Start at the configured values rather than smoothing towards them from the defaults
+            for (auto* v : { &f.morphSmooth, &f.intensitySmooth, &f.driveSmooth, &f.mixSmooth })
+                v->setCurrentAndTargetValue(v->getTargetValue());
+            f.snapMorphPole();
+            f.updateCoeffsBlock(block);
+            return f;
+        }
+
+        std::array<float,12> shapeA{}, shapeB{};
+        double sr { REFERENCE_SR };
+        int block { 512 };
+        float morphValue { 0.5f };
+        float intensityValue { AUTHENTIC_INTENSITY };
+        float driveValue { AUTHENTIC_DRIVE };
+        float saturationValue { AUTHENTIC_SATURATION };
+        float mixValue { 1.0f };
+        bool geodesicValue { GEODESIC_RADIUS };
     };
 }
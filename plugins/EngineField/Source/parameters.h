@@ -1,15 +1,28 @@
 
 #pragma once
 #include <juce_audio_processors/juce_audio_processors.h>
+#include "dsp/ZPlaneFilter.h" // intensityPerceptual, SHAPE_PAIRS
 
 namespace enginefield::params
 {
     static constexpr auto characterId = "character";
     static constexpr auto mixId       = "mix";
+    static constexpr auto intensityId = "intensity";
+    static constexpr auto driveId     = "drive";
+    static constexpr auto saturationId = "saturation";
+    static constexpr auto envDepthId  = "envDepth";
+    static constexpr auto keytrackId  = "keytrack";
+    static constexpr auto tuneId      = "tune";
     static constexpr auto gainId      = "gain";
+    static constexpr auto inputGainId = "inputGain";
     static constexpr auto bypassId    = "bypass";
+    static constexpr auto hostBypassId = "hostBypass";
     static constexpr auto testToneId  = "testTone";
     static constexpr auto effectModeId = "effectMode";
+    static constexpr auto outputInvertId = "outputInvert";
+    static constexpr auto sidechainId = "sidechain";
+    static constexpr auto outputLimitId = "outputLimit";
+    static constexpr auto shapePairId = "shapePair";
 
     inline juce::AudioProcessorValueTreeState::ParameterLayout createLayout()
     {
@@ -28,6 +41,62 @@ namespace enginefield::params
             juce::AudioParameterFloatAttributes().withLabel("%")
         ));
 
+        // Resonance sharpness; default matches the previously fixed authentic 40%.
+        // The value is the intensity itself (state stays compatible); knob travel follows the perceptual
+        // curve, so more of the range lands in the high-resonance region
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ intensityId, 1 }, "Intensity",
+            juce::NormalisableRange<float>{ 0.0f, 100.0f,
+                [](float, float, float knob)  { return 100.0f * emu::intensityPerceptual(knob); },
+                [](float, float, float value) { return emu::intensityPerceptualInverse(value * 0.01f); },
+                [](float, float, float value) { return juce::jlimit(0.0f, 100.0f, std::round(value * 100.0f) * 0.01f); } },
+            40.0f,
+            juce::AudioParameterFloatAttributes().withLabel("%")
+        ));
+
+        // Pre-drive amount (tanh on the filter input); default matches the previously fixed authentic 20%
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ driveId, 1 }, "Drive",
+            juce::NormalisableRange<float>{ 0.0f, 100.0f, 0.01f }, 20.0f,
+            juce::AudioParameterFloatAttributes().withLabel("%")
+        ));
+
+        // Per-section tanh saturation; default matches the previously fixed authentic 20%
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ saturationId, 1 }, "Saturation",
+            juce::NormalisableRange<float>{ 0.0f, 100.0f, 0.01f }, 20.0f,
+            juce::AudioParameterFloatAttributes().withLabel("%")
+        ));
+
+        // Envelope -> CHARACTER modulation amount; negative inverts (louder input morphs downward).
+        // Default matches the previously fixed +20%
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ envDepthId, 1 }, "Env Depth",
+            juce::NormalisableRange<float>{ -100.0f, 100.0f, 0.01f }, 20.0f,
+            juce::AudioParameterFloatAttributes().withLabel("%")
+        ));
+
+        // MIDI keytracking: blends CHARACTER (0%) towards the last played note's morph position (100%)
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ keytrackId, 1 }, "Keytrack",
+            juce::NormalisableRange<float>{ 0.0f, 100.0f, 0.01f }, 0.0f,
+            juce::AudioParameterFloatAttributes().withLabel("%")
+        ));
+
+        // Tune: shifts all pole frequencies (formant shift)
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ tuneId, 1 }, "Tune",
+            juce::NormalisableRange<float>{ -24.0f, 24.0f, 0.01f }, 0.0f,
+            juce::AudioParameterFloatAttributes().withLabel("st")
+        ));
+
+        // Input trim: applied inside the filter ahead of drive; the mix dry tap is selectable (default post-trim)
+        ps.push_back(std::make_unique<juce::AudioParameterFloat>(
+            ParameterID{ inputGainId, 1 }, "Input",
+            juce::NormalisableRange<float>{ -24.0f, 24.0f, 0.01f }, 0.0f,
+            juce::AudioParameterFloatAttributes().withLabel("dB")
+        ));
+
         ps.push_back(std::make_unique<juce::AudioParameterFloat>(
             ParameterID{ gainId, 1 }, "Output",
             juce::NormalisableRange<float>{ -12.0f, 12.0f, 0.01f }, 0.0f,
@@ -38,6 +107,11 @@ namespace enginefield::params
             ParameterID{ bypassId, 1 }, "Bypass", false
         ));
 
+        // Host soft bypass (VST3/CLAP): exposed via getBypassParameter(), shares the bypass crossfade
+        ps.push_back(std::make_unique<juce::AudioParameterBool>(
+            ParameterID{ hostBypassId, 1 }, "Host Bypass", false
+        ));
+
         ps.push_back(std::make_unique<juce::AudioParameterBool>(
             ParameterID{ testToneId, 1 }, "Test Tone (440Hz)", false
         ));
@@ -46,6 +120,28 @@ namespace enginefield::params
             ParameterID{ effectModeId, 1 }, "EFFECT (Wet Solo)", false
         ));
 
+        ps.push_back(std::make_unique<juce::AudioParameterBool>(
+            ParameterID{ outputInvertId, 1 }, "Output Invert", false
+        ));
+
+        // Envelope follower listens to the sidechain bus instead of the main input (when the host connects one)
+        ps.push_back(std::make_unique<juce::AudioParameterBool>(
+            ParameterID{ sidechainId, 1 }, "Sidechain", false
+        ));
+
+        // Soft output limiter after the output gain (transparent below -6 dBFS, ceiling 0 dBFS)
+        ps.push_back(std::make_unique<juce::AudioParameterBool>(
+            ParameterID{ outputLimitId, 1 }, "Output Limit", false
+        ));
+
+        // Built-in shape pair, by SHAPE_PAIRS index; default is the previously fixed Vowel pair
+        juce::StringArray pairNames;
+        for (const auto& pair : emu::SHAPE_PAIRS)
+            pairNames.add(pair.name);
+        ps.push_back(std::make_unique<juce::AudioParameterChoice>(
+            ParameterID{ shapePairId, 1 }, "Shape", pairNames, 0
+        ));
+
         return { ps.begin(), ps.end() };
     }
 }
@@ -0,0 +1,159 @@
+#include "../plugins/EngineField/Source/dsp/EnvelopeFollower.h"
+#include "../plugins/EngineField/Source/dsp/TestSignals.h"
+
+#include <juce_core/juce_core.h>
+
+class EnvelopeFollowerTests : public juce::UnitTest
+{
+public:
+    EnvelopeFollowerTests() : juce::UnitTest("EnvelopeFollower", "EngineField") {}
+
+    void runTest() override
+    {
+        blockPeak();
+        detectorHighPass();
+        rmsDetection();
+        holdStage();
+    }
+
+private:
+    static constexpr double SampleRate = 48000.0;
+
+    // synth-229
+    void blockPeak()
+    {
+        beginTest("processBlock ends in the same state as a per-sample loop");
+
+        emu::EnvelopeFollower block, loop;
+        block.prepare(SampleRate);
+        loop.prepare(SampleRate);
+
+        const auto input = emu::testsignals::whiteNoise(7, 4096);
+        for (size_t start = 0; start < input.size(); start += 512)
+        {
+            const float blockEnv = block.processBlock(input.data() + start, 512);
+            float loopEnv = 0.0f;
+            for (size_t n = start; n < start + 512; ++n)
+                loopEnv = loop.process(input[n]);
+
+            expectEquals(blockEnv, loopEnv);
+            expectEquals(block.state, loop.state);
+        }
+
+        // An empty block reports the current envelope without advancing
+        const float before = block.state;
+        expectEquals(block.processBlock(input.data(), 0), std::clamp(before * block.depth, 0.0f, 1.0f));
+        expectEquals(block.state, before);
+    }
+
+    // synth-238
+    void detectorHighPass()
+    {
+        beginTest("High-pass detector follows treble bursts and ignores a low sine");
+
+        // Settled envelope for a 0.5-amplitude sine
+        auto settledEnvelope = [](emu::DetectorFilter mode, float toneHz)
+        {
+            emu::EnvelopeFollower env;
+            env.prepare(SampleRate);
+            env.setDetectorFilter(mode, 2000.0f);
+            auto tone = emu::testsignals::sine(toneHz, 48000, static_cast<float>(SampleRate));
+            for (auto& x : tone) x *= 0.5f;
+            float peak = 0.0f;
+            for (size_t n = 0; n < tone.size(); ++n)
+            {
+                const float e = env.process(tone[n]);
+                if (n >= tone.size() / 2) peak = std::max(peak, e);
+            }
+            return peak;
+        };
+
+        const float lowPlain = settledEnvelope(emu::DetectorFilter::None, 60.0f);
+        const float lowHp = settledEnvelope(emu::DetectorFilter::HighPass, 60.0f);
+        const float trebleHp = settledEnvelope(emu::DetectorFilter::HighPass, 8000.0f);
+        expectGreaterThan(lowPlain, 0.4f);
+        expectLessThan(lowHp, 0.1f * lowPlain, "high-pass detector still follows a 60 Hz sine");
+        expectGreaterThan(trebleHp, 0.3f, "high-pass detector missed an 8 kHz burst");
+
+        // A treble burst over a running bass line: the envelope rises only while the burst plays
+        emu::EnvelopeFollower env;
+        env.prepare(SampleRate);
+        env.setDetectorFilter(emu::DetectorFilter::HighPass, 2000.0f);
+        const auto bass = emu::testsignals::sine(60.0f, 48000, static_cast<float>(SampleRate));
+        const auto treble = emu::testsignals::sine(8000.0f, 48000, static_cast<float>(SampleRate));
+        float beforeBurst = 0.0f, duringBurst = 0.0f;
+        for (size_t n = 0; n < bass.size(); ++n)
+        {
+            const bool burst = n >= 24000 && n < 28800;
+            const float e = env.process(0.5f * bass[n] + (burst ? 0.5f * treble[n] : 0.0f));
+            if (n >= 12000 && n < 24000) beforeBurst = std::max(beforeBurst, e);
+            if (burst) duringBurst = std::max(duringBurst, e);
+        }
+        expectGreaterThan(duringBurst, 5.0f * beforeBurst);
+    }
+
+    // synth-271
+    void rmsDetection()
+    {
+        beginTest("RMS detection sits near 0.707x the peak envelope on a sine");
+
+        // Rms smooths x^2 with attack/release, so it gets a symmetric 50 ms ballistic to read the mean
+        auto settled = [](emu::DetectionMode mode)
+        {
+            emu::EnvelopeFollower env;
+            env.prepare(SampleRate);
+            env.setDepth(1.0f);
+            env.setDetectionMode(mode);
+            if (mode == emu::DetectionMode::Rms)
+            {
+                env.setAttackMs(50.0f);
+                env.setReleaseMs(50.0f);
+            }
+            const auto tone = emu::testsignals::sine(200.0f, 48000, static_cast<float>(SampleRate));
+            float peak = 0.0f;
+            for (size_t n = 0; n < tone.size(); ++n)
+            {
+                const float e = env.process(0.5f * tone[n]);
+                if (n >= tone.size() / 2) peak = std::max(peak, e);
+            }
+            return peak;
+        };
+
+        const float peak = settled(emu::DetectionMode::Peak), rms = settled(emu::DetectionMode::Rms);
+        expectWithinAbsoluteError(peak, 0.5f, 0.01f);
+        expectWithinAbsoluteError(rms / peak, 0.7071f, 0.03f);
+
+        // Peak stays the default
+        expect(emu::EnvelopeFollower{}.detectionMode == emu::DetectionMode::Peak);
+    }
+
+    // synth-272
+    void holdStage()
+    {
+        beginTest("Hold keeps the envelope flat for the hold window after a brief impulse");
+
+        emu::EnvelopeFollower env;
+        env.prepare(SampleRate);
+        env.setDepth(1.0f);
+        env.setHoldMs(10.0f); // 480 samples
+
+        const float top = env.process(1.0f);
+        expectGreaterThan(top, 0.0f);
+        for (int n = 0; n < 480; ++n)
+            expectEquals(env.process(0.0f), top);
+
+        // Then the release takes over
+        const float released = env.process(0.0f);
+        expectLessThan(released, top);
+        expectLessThan(env.process(0.0f), released);
+
+        // Without hold the release starts on the next sample
+        emu::EnvelopeFollower plain;
+        plain.prepare(SampleRate);
+        plain.setDepth(1.0f);
+        const float plainTop = plain.process(1.0f);
+        expectLessThan(plain.process(0.0f), plainTop);
+    }
+};
+
+static EnvelopeFollowerTests envelopeFollowerTests;
@@ -0,0 +1,678 @@
+#include "../plugins/EngineField/Source/FieldProcessor.h"
+
+#include <juce_core/juce_core.h>
+#include <vector>
+
+namespace
+{
+    constexpr double kSampleRate = 48000.0;
+    constexpr int kBlockSize = 512;
+
+    // 0.25-amplitude sine continuing across blocks (block index -> absolute sample position)
+    void fillSine(juce::AudioBuffer<float>& buffer, int blockIndex, float freqHz = 440.0f)
+    {
+        for (int ch = 0; ch < buffer.getNumChannels(); ++ch)
+            for (int i = 0; i < buffer.getNumSamples(); ++i)
+            {
+                const double n = static_cast<double>(blockIndex) * buffer.getNumSamples() + i;
+                buffer.setSample(ch, i, 0.25f * static_cast<float>(std::sin(juce::MathConstants<double>::twoPi * freqHz * n / kSampleRate)));
+            }
+    }
+
+    float maxStep(const float* x, int n, float previous)
+    {
+        float m = std::abs(x[0] - previous);
+        for (int i = 1; i < n; ++i) m = std::max(m, std::abs(x[i] - x[i - 1]));
+        return m;
+    }
+
+    void setParam(FieldProcessor& p, const char* id, float value)
+    {
+        auto* param = p.getAPVTS().getParameter(id);
+        param->setValueNotifyingHost(param->convertTo0to1(value));
+    }
+
+    // Builds a checksummed share code by hand, with every setting at `value` (0..1)
+    juce::String makeShareCode(juce::uint8 version, const char* shapeName, float value)
+    {
+        juce::MemoryOutputStream payload;
+        payload.writeString(shapeName);
+        for (int i = 0; i < 4; ++i)
+            payload.writeFloat(value);
+
+        juce::uint32 hash = 2166136261u;
+        for (size_t i = 0; i < payload.getDataSize(); ++i)
+            hash = (hash ^ static_cast<const juce::uint8*>(payload.getData())[i]) * 16777619u;
+
+        juce::MemoryOutputStream out;
+        out.writeByte(static_cast<char>(version));
+        out.writeShort(static_cast<short>(payload.getDataSize()));
+        out.write(payload.getData(), payload.getDataSize());
+        out.writeInt(static_cast<int>(hash));
+        return juce::Base64::toBase64(out.getData(), out.getDataSize())
+                   .replaceCharacter('+', '-').replaceCharacter('/', '_').trimCharactersAtEnd("=");
+    }
+}
+
+class FieldProcessorTests : public juce::UnitTest
+{
+public:
+    FieldProcessorTests() : juce::UnitTest("FieldProcessor", "EngineField") {}
+
+    void runTest() override
+    {
+        intensityParameter();
+        hostBypass();
+        gainSmoothing();
+        envelopeRouting();
+        outputInvert();
+        envelopeOutput();
+        maxRadiusOutput();
+        intensityDefault();
+        driveParameter();
+        shareCode();
+        envelopeMeter();
+        envelopeDepth();
+        sidechainSelection();
+        noteToMorph();
+        vowelSequenceHandover();
+        keytrackReset();
+        shapePairParameter();
+    }
+
+private:
+    // synth-204
+    void intensityParameter()
+    {
+        beginTest("Intensity parameter value is the intensity, knob travel is perceptual");
+
+        FieldProcessor p;
+        auto* param = p.getAPVTS().getParameter(enginefield::params::intensityId);
+        expectWithinAbsoluteError(param->convertFrom0to1(param->getDefaultValue()), 40.0f, 0.01f);
+        expectGreaterThan(param->convertFrom0to1(0.5f), 50.0f);
+
+        setParam(p, enginefield::params::intensityId, 75.0f);
+        expectWithinAbsoluteError(p.getAPVTS().getRawParameterValue(enginefield::params::intensityId)->load(), 75.0f, 0.01f);
+    }
+
+    // synth-207
+    void hostBypass()
+    {
+        beginTest("Host soft bypass crossfades to the dry signal");
+
+        FieldProcessor p;
+        p.prepareToPlay(kSampleRate, kBlockSize);
+        juce::AudioBuffer<float> buffer(2, kBlockSize);
+        juce::MidiBuffer midi;
+
+        int block = 0;
+        float wetStep = 0.0f, last = 0.0f;
+        for (; block < 20; ++block)
+        {
+            fillSine(buffer, block);
+            p.processBlock(buffer, midi);
+            wetStep = maxStep(buffer.getReadPointer(0), kBlockSize, last);
+            last = buffer.getSample(0, kBlockSize - 1);
+        }
+
+        p.getBypassParameter()->setValueNotifyingHost(1.0f);
+        float transitionStep = 0.0f;
+        for (int i = 0; i < 4; ++i, ++block)
+        {
+            fillSine(buffer, block);
+            p.processBlock(buffer, midi);
+            transitionStep = std::max(transitionStep, maxStep(buffer.getReadPointer(0), kBlockSize, last));
+            last = buffer.getSample(0, kBlockSize - 1);
+        }
+
+        // Fully bypassed: output is the input
+        juce::AudioBuffer<float> input(2, kBlockSize);
+        fillSine(input, block - 1);
+        float diff = 0.0f;
+        for (int i = 0; i < kBlockSize; ++i)
+            diff = std::max(diff, std::abs(buffer.getSample(0, i) - input.getSample(0, i)));
+        expectLessThan(diff, 1.0e-5f, "host bypass did not reach the dry path");
+
+        // The crossfade adds at most |wet - dry| / 480 per sample; a hard switch would add the full difference
+        expectLessThan(transitionStep, wetStep + 0.02f, "bypass transition is not smooth");
+
+        // The output stage is bypassed as well: output gain and invert leave the dry signal untouched
+        FieldProcessor q;
+        setParam(q, enginefield::params::gainId, -6.0f);
+        setParam(q, enginefield::params::outputInvertId, 1.0f);
+        q.getBypassParameter()->setValueNotifyingHost(1.0f);
+        q.prepareToPlay(kSampleRate, kBlockSize);
+        for (block = 0; block < 20; ++block)
+        {
+            fillSine(buffer, block);
+            q.processBlock(buffer, midi);
+        }
+        fillSine(input, block - 1);
+        diff = 0.0f;
+        for (int ch = 0; ch < 2; ++ch)
+            for (int i = 0; i < kBlockSize; ++i)
+                diff = std::max(diff, std::abs(buffer.getSample(ch, i) - input.getSample(ch, i)));
+        expectEquals(diff, 0.0f, "bypassed output went through the output gain or invert");
+    }
+
+    // synth-216
+    void gainSmoothing()
+    {
+        beginTest("Longer gain smoothing slows the response to an output gain step");
+
+        // Output peak over the second half of the block after a 0 -> -12 dB step, relative to before it
+        auto levelAfterStep = [](float smoothingMs)
+        {
+            FieldProcessor p;
+            p.setGainSmoothingMs(smoothingMs);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+
+            int block = 0;
+            for (; block < 20; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+            }
+            const float before = buffer.getMagnitude(0, kBlockSize / 2, kBlockSize / 2);
+
+            setParam(p, enginefield::params::gainId, -12.0f);
+            fillSine(buffer, block);
+            p.processBlock(buffer, midi);
+            return buffer.getMagnitude(0, kBlockSize / 2, kBlockSize / 2) / before;
+        };
+
+        const float fast = levelAfterStep(2.0f), slow = levelAfterStep(200.0f);
+        expectLessThan(fast, 0.35f, "short smoothing did not reach -12 dB within the block");
+        expectGreaterThan(slow, 0.8f, "long smoothing followed the step too quickly");
+    }
+
+    // synth-230
+    void envelopeRouting()
+    {
+        beginTest("Opposite envelope polarities make the L and R outputs diverge");
+
+        // Largest L/R difference over the last block, same sine fed to both channels
+        auto channelDifference = [](float depthL, float depthR)
+        {
+            FieldProcessor p;
+            setParam(p, enginefield::params::envDepthId, 100.0f);
+            p.setEnvelopeRouting(depthL, depthR);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+
+            for (int block = 0; block < 20; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+            }
+            float diff = 0.0f;
+            for (int i = 0; i < kBlockSize; ++i)
+                diff = std::max(diff, std::abs(buffer.getSample(0, i) - buffer.getSample(1, i)));
+            return diff;
+        };
+
+        expectLessThan(channelDifference(1.0f, 1.0f), 1.0e-5f, "shared routing split the channels");
+        expectGreaterThan(channelDifference(1.0f, -1.0f), 0.01f, "opposite routing left the channels identical");
+    }
+
+    // synth-232
+    void outputInvert()
+    {
+        beginTest("Output invert negates both channels");
+
+        auto lastBlock = [](bool invert)
+        {
+            FieldProcessor p;
+            setParam(p, enginefield::params::outputInvertId, invert ? 1.0f : 0.0f);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+            for (int block = 0; block < 20; ++block) // past the 20 ms gain ramp
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+            }
+            return buffer;
+        };
+
+        const auto normal = lastBlock(false), inverted = lastBlock(true);
+        expectGreaterThan(normal.getMagnitude(0, 0, kBlockSize), 0.01f);
+        float diff = 0.0f;
+        for (int ch = 0; ch < 2; ++ch)
+            for (int i = 0; i < kBlockSize; ++i)
+                diff = std::max(diff, std::abs(inverted.getSample(ch, i) + normal.getSample(ch, i)));
+        expectLessThan(diff, 1.0e-6f, "inverted output is not the negated output");
+
+        // Toggling invert flips the sign at once, without ramping the output gain through zero
+        FieldProcessor a, b;
+        for (auto* p : { &a, &b })
+        {
+            p->setGainSmoothingMs(500.0f);
+            p->prepareToPlay(kSampleRate, kBlockSize);
+        }
+        juce::AudioBuffer<float> bufA(2, kBlockSize), bufB(2, kBlockSize);
+        juce::MidiBuffer midi;
+        for (int block = 0; block < 4; ++block)
+        {
+            if (block == 2)
+                setParam(b, enginefield::params::outputInvertId, 1.0f);
+            fillSine(bufA, block);
+            fillSine(bufB, block);
+            a.processBlock(bufA, midi);
+            b.processBlock(bufB, midi);
+        }
+        diff = 0.0f;
+        for (int i = 0; i < kBlockSize; ++i)
+            diff = std::max(diff, std::abs(bufB.getSample(0, i) + bufA.getSample(0, i)));
+        expectLessThan(diff, 1.0e-6f, "invert toggle went through the gain ramp");
+    }
+
+    // synth-246
+    void envelopeOutput()
+    {
+        beginTest("Envelope output reports the envelope of a known input level, outside the parameter state");
+
+        FieldProcessor p;
+        p.prepareToPlay(kSampleRate, kBlockSize);
+        expect(p.getAPVTS().getParameter("envelopeOut") == nullptr, "envelope output is a host parameter");
+
+        juce::AudioBuffer<float> buffer(2, kBlockSize);
+        juce::MidiBuffer midi;
+        for (int block = 0; block < 20; ++block)
+        {
+            fillSine(buffer, block);
+            p.processBlock(buffer, midi);
+        }
+
+        // Peak follower at depth 0.75 on a 0.25-amplitude sine (small release ripple below the peak)
+        expectWithinAbsoluteError(p.getEnvelopeValue(), 0.75f * 0.25f, 0.01f);
+
+        // Silence: the output releases back toward zero
+        for (int block = 0; block < 100; ++block)
+        {
+            buffer.clear();
+            p.processBlock(buffer, midi);
+        }
+        expectLessThan(p.getEnvelopeValue(), 0.01f);
+
+        // A fresh prepare starts the meter from zero
+        p.prepareToPlay(kSampleRate, kBlockSize);
+        expectEquals(p.getEnvelopeValue(), 0.0f);
+    }
+
+    // synth-256~2
+    void maxRadiusOutput()
+    {
+        beginTest("Max pole radius meter rises toward 100% with intensity, outside the parameter state");
+
+        auto radiusPctAt = [](float intensityPct)
+        {
+            FieldProcessor p;
+            setParam(p, enginefield::params::intensityId, intensityPct);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+            for (int block = 0; block < 10; ++block) // past the 20 ms intensity ramp; silence keeps the envelope still
+            {
+                buffer.clear();
+                p.processBlock(buffer, midi);
+            }
+            return p.getMaxPoleRadius();
+        };
+
+        const float low = radiusPctAt(0.0f), mid = radiusPctAt(20.0f), high = radiusPctAt(100.0f);
+        expectGreaterThan(low, 50.0f);
+        expectGreaterThan(mid, low);
+        expectGreaterThan(high, mid);
+
+        // Full intensity drives the strongest pole into the hardware cap
+        expectWithinAbsoluteError(high, 100.0f * emu::MAX_POLE_RADIUS, 0.01f);
+
+        FieldProcessor p;
+        expect(p.getAPVTS().getParameter("maxRadiusOut") == nullptr, "max radius meter is a host parameter");
+    }
+
+    // synth-261~2
+    void intensityDefault()
+    {
+        beginTest("Default intensity is the authentic 0.4 and sounds like an explicit 40%");
+
+        FieldProcessor fresh;
+        const float normalized = fresh.getAPVTS().getRawParameterValue(enginefield::params::intensityId)->load() * 0.01f;
+        expectWithinAbsoluteError(normalized, 0.4f, 1.0e-4f);
+        expectWithinAbsoluteError(normalized, emu::AUTHENTIC_INTENSITY, 1.0e-4f);
+
+        auto render = [](float intensityPct)
+        {
+            FieldProcessor p;
+            if (intensityPct >= 0.0f)
+                setParam(p, enginefield::params::intensityId, intensityPct);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+            std::vector<float> out;
+            for (int block = 0; block < 8; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+                out.insert(out.end(), buffer.getReadPointer(0), buffer.getReadPointer(0) + kBlockSize);
+            }
+            return out;
+        };
+
+        const auto byDefault = render(-1.0f);
+        const auto explicit40 = render(40.0f);
+        const auto sharper = render(90.0f);
+        float diff = 0.0f, moved = 0.0f;
+        for (size_t i = 0; i < byDefault.size(); ++i)
+        {
+            diff = std::max(diff, std::abs(byDefault[i] - explicit40[i]));
+            moved = std::max(moved, std::abs(byDefault[i] - sharper[i]));
+        }
+        expectLessThan(diff, 1.0e-6f);
+        expectGreaterThan(moved, 1.0e-3f, "intensity parameter does not reach the filter");
+    }
+
+    // synth-262
+    void driveParameter()
+    {
+        beginTest("Drive parameter defaults to the authentic 20% and reaches the pre-drive");
+
+        FieldProcessor fresh;
+        expectWithinAbsoluteError(fresh.getAPVTS().getRawParameterValue(enginefield::params::driveId)->load() * 0.01f,
+                                  emu::AUTHENTIC_DRIVE, 1.0e-4f);
+
+        auto render = [](float drivePct)
+        {
+            FieldProcessor p;
+            setParam(p, enginefield::params::driveId, drivePct);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+            std::vector<float> out;
+            for (int block = 0; block < 8; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+                out.insert(out.end(), buffer.getReadPointer(0), buffer.getReadPointer(0) + kBlockSize);
+            }
+            return out;
+        };
+
+        const auto authentic = render(20.0f);
+        const auto heavy = render(100.0f);
+        float moved = 0.0f;
+        for (size_t i = 0; i < authentic.size(); ++i)
+            moved = std::max(moved, std::abs(authentic[i] - heavy[i]));
+        expectGreaterThan(moved, 1.0e-3f, "drive parameter does not reach the filter");
+    }
+
+    // synth-264~2
+    void shareCode()
+    {
+        beginTest("Share codes are URL-safe, round-trip the key settings and reject corrupted input");
+
+        FieldProcessor source;
+        setParam(source, enginefield::params::characterId, 63.0f);
+        setParam(source, enginefield::params::intensityId, 72.0f);
+        setParam(source, enginefield::params::driveId, 35.0f);
+        setParam(source, enginefield::params::saturationId, 8.0f);
+
+        const auto code = source.toShareCode();
+        expect(code.isNotEmpty());
+        expect(code.containsOnly("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"), "not URL-safe: " + code);
+
+        auto value = [](FieldProcessor& p, const char* id) { return p.getAPVTS().getRawParameterValue(id)->load(); };
+
+        FieldProcessor target;
+        expect(target.fromShareCode(code));
+        for (const char* id : { enginefield::params::characterId, enginefield::params::intensityId,
+                                enginefield::params::driveId, enginefield::params::saturationId })
+            expectWithinAbsoluteError(value(target, id), value(source, id), 0.01f);
+
+        // Corrupted codes are rejected and leave the settings alone
+        FieldProcessor untouched;
+        const float before = value(untouched, enginefield::params::intensityId);
+        auto flipped = code;
+        flipped = flipped.substring(0, 6) + (flipped[6] == 'A' ? "B" : "A") + flipped.substring(7);
+        for (const auto& bad : { flipped, code.dropLastCharacters(3), juce::String("not a share code!"), juce::String() })
+            expect(! untouched.fromShareCode(bad), "accepted corrupted code: " + bad);
+        expectEquals(value(untouched, enginefield::params::intensityId), before);
+
+        // Well-formed codes from another format version or with an unknown shape pair are refused too
+        expect(untouched.fromShareCode(makeShareCode(1, "Vowel", 0.5f)));
+        expect(! untouched.fromShareCode(makeShareCode(2, "Vowel", 0.9f)), "accepted a newer version");
+        expect(! untouched.fromShareCode(makeShareCode(1, "Nope", 0.9f)), "accepted an unknown shape pair");
+        expectWithinAbsoluteError(value(untouched, enginefield::params::intensityId), 50.0f, 0.01f);
+    }
+
+    // synth-273
+    void envelopeMeter()
+    {
+        beginTest("Envelope meter value follows the modulation envelope once per block");
+
+        FieldProcessor p;
+        p.prepareToPlay(kSampleRate, kBlockSize);
+        expectEquals(p.getEnvelopeValue(), 0.0f);
+
+        juce::AudioBuffer<float> buffer(2, kBlockSize);
+        juce::MidiBuffer midi;
+        for (int block = 0; block < 20; ++block)
+        {
+            fillSine(buffer, block);
+            p.processBlock(buffer, midi);
+        }
+        expectWithinAbsoluteError(p.getEnvelopeValue(), 0.75f * 0.25f, 0.01f);
+
+        for (int block = 0; block < 100; ++block)
+        {
+            buffer.clear();
+            p.processBlock(buffer, midi);
+        }
+        expectLessThan(p.getEnvelopeValue(), 0.01f);
+    }
+
+    // synth-274
+    void envelopeDepth()
+    {
+        beginTest("Negative envelope depth lowers CHARACTER as the input gets louder");
+
+        float last = FieldProcessor::envelopeMorph(0.5f, 0.0f, -0.4f);
+        expectEquals(last, 0.5f);
+        for (float env : { 0.2f, 0.5f, 1.0f })
+        {
+            const float m = FieldProcessor::envelopeMorph(0.5f, env, -0.4f);
+            expectLessThan(m, last);
+            last = m;
+        }
+        expectWithinAbsoluteError(last, 0.1f, 1.0e-6f);
+
+        // Default +20% keeps the previous upward modulation; the result stays in range
+        expectWithinAbsoluteError(FieldProcessor::envelopeMorph(0.5f, 1.0f, 0.2f), 0.7f, 1.0e-6f);
+        expectEquals(FieldProcessor::envelopeMorph(0.1f, 1.0f, -1.0f), 0.0f);
+        expectEquals(FieldProcessor::envelopeMorph(0.9f, 1.0f, 1.0f), 1.0f);
+
+        // Inverted channel routing flips a negative depth back upward
+        expectGreaterThan(FieldProcessor::envelopeMorph(0.5f, 0.5f, -0.4f, -1.0f), 0.5f);
+
+        FieldProcessor p;
+        auto* depth = p.getAPVTS().getParameter(enginefield::params::envDepthId);
+        expectWithinAbsoluteError(depth->convertFrom0to1(depth->getDefaultValue()), 20.0f, 0.01f);
+        expectWithinAbsoluteError(depth->convertFrom0to1(0.0f), -100.0f, 0.01f);
+    }
+
+    // synth-275
+    void sidechainSelection()
+    {
+        beginTest("Envelope reads the sidechain only when enabled and connected, else the main input");
+
+        const float mainLeft[1] = { 0.0f }, sidechain[1] = { 0.0f };
+        expect(FieldProcessor::selectEnvelopeSource(mainLeft, sidechain, true) == sidechain);
+        expect(FieldProcessor::selectEnvelopeSource(mainLeft, sidechain, false) == mainLeft);
+        expect(FieldProcessor::selectEnvelopeSource(mainLeft, nullptr, true) == mainLeft);
+
+        // Envelope after 20 blocks of a sine at the given main/sidechain levels (4 channels with the bus enabled)
+        auto envelopeAfter = [](bool enableBus, bool sidechainOn, float mainLevel, float sidechainLevel)
+        {
+            FieldProcessor p;
+            if (enableBus)
+                p.enableAllBuses();
+            setParam(p, enginefield::params::sidechainId, sidechainOn ? 1.0f : 0.0f);
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(enableBus ? 4 : 2, kBlockSize);
+            juce::MidiBuffer midi;
+            for (int block = 0; block < 20; ++block)
+            {
+                fillSine(buffer, block);
+                for (int ch = 0; ch < buffer.getNumChannels(); ++ch)
+                    buffer.applyGain(ch, 0, kBlockSize, ch < 2 ? mainLevel : sidechainLevel);
+                p.processBlock(buffer, midi);
+            }
+            return p.getEnvelopeValue();
+        };
+
+        const float mainOnly = envelopeAfter(false, false, 1.0f, 0.0f);
+        expectGreaterThan(mainOnly, 0.1f);
+        expectEquals(envelopeAfter(false, true, 1.0f, 0.0f), mainOnly, "missing sidechain bus did not fall back");
+        expectLessThan(envelopeAfter(true, true, 1.0f, 0.0f), 0.01f, "enabled sidechain still follows the main input");
+        expectWithinAbsoluteError(envelopeAfter(true, true, 0.0f, 1.0f), mainOnly, 0.01f);
+        expectEquals(envelopeAfter(true, false, 1.0f, 0.0f), mainOnly);
+    }
+
+    // synth-276
+    void noteToMorph()
+    {
+        beginTest("Keytracking maps C2..C7 linearly onto the morph range and clamps outside");
+
+        expectEquals(FieldProcessor::noteToMorph(FieldProcessor::kKeytrackLowNote), 0.0f);
+        expectEquals(FieldProcessor::noteToMorph(FieldProcessor::kKeytrackHighNote), 1.0f);
+        expectWithinAbsoluteError(FieldProcessor::noteToMorph(66), 0.5f, 1.0e-6f);
+        expectWithinAbsoluteError(FieldProcessor::noteToMorph(48), 0.2f, 1.0e-6f);
+        expectEquals(FieldProcessor::noteToMorph(0), 0.0f);
+        expectEquals(FieldProcessor::noteToMorph(127), 1.0f);
+
+        for (int note = 1; note < 128; ++note)
+            expectGreaterOrEqual(FieldProcessor::noteToMorph(note), FieldProcessor::noteToMorph(note - 1));
+
+        // Keytracking is opt-in: the amount defaults to 0 so CHARACTER alone sets the morph
+        FieldProcessor p;
+        expect(p.acceptsMidi());
+        expectEquals(p.getAPVTS().getRawParameterValue(enginefield::params::keytrackId)->load(), 0.0f);
+    }
+
+    // synth-214
+    void vowelSequenceHandover()
+    {
+        beginTest("Vowel sequences queue lock-free and take over the morph from the next block");
+
+        FieldProcessor p;
+        p.prepareToPlay(kSampleRate, kBlockSize);
+        juce::AudioBuffer<float> buffer(2, kBlockSize);
+        juce::MidiBuffer midi;
+
+        auto settledTheta = [&](float position)
+        {
+            expect(p.setVowelSequence(&position, 1, 1.0f));
+            for (int block = 0; block < 20; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+            }
+            return p.getUIPoles()[1].load();
+        };
+        const float low = settledTheta(0.0f);
+        const float high = settledTheta(1.0f);
+        expectGreaterThan(std::abs(high - low), 1.0e-3f, "queued sequence did not reach the filter");
+
+        // The queue holds a few sequences between blocks and refuses more until the audio thread drains it
+        const float position = 0.5f;
+        int accepted = 0;
+        while (accepted < 16 && p.setVowelSequence(&position, 1, 1.0f))
+            ++accepted;
+        expectGreaterThan(accepted, 0);
+        expectLessThan(accepted, 16);
+        p.processBlock(buffer, midi);
+        expect(p.setVowelSequence(&position, 0, 1.0f));
+    }
+
+    // synth-276
+    void keytrackReset()
+    {
+        beginTest("prepareToPlay forgets the last note, so keytracking waits for a new one");
+
+        juce::AudioBuffer<float> buffer(2, kBlockSize);
+        auto settledTheta = [&buffer](FieldProcessor& p, juce::MidiBuffer& midi)
+        {
+            for (int block = 0; block < 20; ++block)
+            {
+                fillSine(buffer, block);
+                p.processBlock(buffer, midi);
+                midi.clear();
+            }
+            return p.getUIPoles()[1].load();
+        };
+
+        FieldProcessor fresh, played;
+        for (auto* p : { &fresh, &played })
+        {
+            setParam(*p, enginefield::params::keytrackId, 100.0f);
+            p->prepareToPlay(kSampleRate, kBlockSize);
+        }
+
+        juce::MidiBuffer none, noteOn;
+        noteOn.addEvent(juce::MidiMessage::noteOn(1, FieldProcessor::kKeytrackHighNote, 1.0f), 0);
+        const float reference = settledTheta(fresh, none);
+        expectGreaterThan(std::abs(settledTheta(played, noteOn) - reference), 1.0e-3f, "the note did not move the morph");
+
+        played.releaseResources();
+        played.prepareToPlay(kSampleRate, kBlockSize);
+        expectWithinAbsoluteError(settledTheta(played, none), reference, 1.0e-4f);
+    }
+
+    // synth-221
+    void shapePairParameter()
+    {
+        beginTest("Shape pair parameter loads the pair, survives a state round trip and travels in share codes");
+
+        auto value = [](FieldProcessor& p) { return p.getAPVTS().getRawParameterValue(enginefield::params::shapePairId)->load(); };
+        auto lastBlock = [](FieldProcessor& p)
+        {
+            p.prepareToPlay(kSampleRate, kBlockSize);
+            juce::AudioBuffer<float> buffer(2, kBlockSize);
+            juce::MidiBuffer midi;
+            for (int block = 0; block < 8; ++block)
+            {
+                fillSine(buffer, block, 1000.0f);
+                p.processBlock(buffer, midi);
+            }
+            return buffer;
+        };
+
+        FieldProcessor vowel, bell;
+        expectEquals(value(vowel), 0.0f);
+        setParam(bell, enginefield::params::shapePairId, 1.0f);
+        const auto vowelOut = lastBlock(vowel), bellOut = lastBlock(bell);
+        float diff = 0.0f;
+        for (int i = 0; i < kBlockSize; ++i)
+            diff = std::max(diff, std::abs(vowelOut.getSample(0, i) - bellOut.getSample(0, i)));
+        expectGreaterThan(diff, 1.0e-3f, "Bell pair did not reach the filter");
+
+        juce::MemoryBlock state;
+        bell.getStateInformation(state);
+        FieldProcessor restored;
+        restored.setStateInformation(state.getData(), static_cast<int>(state.getSize()));
+        expectEquals(value(restored), 1.0f);
+
+        // A Bell code switches a Vowel instance over to Bell
+        FieldProcessor target;
+        expect(target.fromShareCode(bell.toShareCode()));
+        expectEquals(value(target), 1.0f);
+        expect(target.fromShareCode(makeShareCode(1, "Low", 0.5f)));
+        expectEquals(value(target), 2.0f);
+    }
+};
+
+static FieldProcessorTests fieldProcessorTests;
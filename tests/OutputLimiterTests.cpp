@@ -0,0 +1,52 @@
+#include "../plugins/EngineField/Source/dsp/OutputLimiter.h"
+#include "../plugins/EngineField/Source/dsp/TestSignals.h"
+
+#include <juce_core/juce_core.h>
+
+class OutputLimiterTests : public juce::UnitTest
+{
+public:
+    OutputLimiterTests() : juce::UnitTest("OutputLimiter", "EngineField") {}
+
+    void runTest() override
+    {
+        ceilingAndTransparency();
+    }
+
+private:
+    static constexpr double SampleRate = 48000.0;
+
+    // synth-280
+    void ceilingAndTransparency()
+    {
+        beginTest("+6 dB input stays at or below 0 dBFS, quiet input passes untouched");
+
+        emu::OutputLimiter limiter;
+        limiter.prepare(SampleRate);
+
+        // +6 dBFS sine on both channels
+        auto left = emu::testsignals::sine(220.0f, 24000, static_cast<float>(SampleRate));
+        for (auto& x : left) x *= 2.0f;
+        auto right = left;
+        limiter.processStereo(left.data(), right.data(), static_cast<int>(left.size()));
+
+        float peak = 0.0f;
+        for (size_t n = 0; n < left.size(); ++n)
+            peak = std::max({ peak, std::abs(left[n]), std::abs(right[n]) });
+        expectLessOrEqual(peak, 1.0f);
+        expectGreaterThan(peak, 0.5f, "limiter squashed the signal below the knee");
+        expectWithinAbsoluteError(limiter.gainReductionDb(), -6.02f, 0.1f);
+
+        // Below -6 dBFS with no reduction pending the limiter is an identity
+        limiter.reset();
+        auto quiet = emu::testsignals::sine(220.0f, 4800, static_cast<float>(SampleRate));
+        for (auto& x : quiet) x *= 0.45f;
+        auto quietL = quiet, quietR = quiet;
+        limiter.processStereo(quietL.data(), quietR.data(), static_cast<int>(quiet.size()));
+        for (size_t n = 0; n < quiet.size(); ++n)
+            expectEquals(quietL[n], quiet[n]);
+        expectEquals(limiter.gainReductionDb(), 0.0f);
+    }
+};
+
+static OutputLimiterTests outputLimiterTests;
@@ -0,0 +1,141 @@
+#include "../plugins/EngineField/Source/dsp/EMUAuthenticTables.h"
+#include "../plugins/EngineField/Source/dsp/ShapeParser.h"
+#include "../plugins/EngineField/Source/dsp/ShapeRegistry.h"
+#include "../plugins/EngineField/Source/dsp/ZPlaneFilter.h"
+
+#include <juce_core/juce_core.h>
+#include <cmath>
+#include <string>
+
+class ShapeTests : public juce::UnitTest
+{
+public:
+    ShapeTests() : juce::UnitTest("Shapes", "EngineField") {}
+
+    void runTest() override
+    {
+        descriptions();
+        textParsing();
+        registry();
+        sharedRangeCheck();
+    }
+
+private:
+    // synth-234
+    void descriptions()
+    {
+        beginTest("Every built-in pair has a description, unknown names have none");
+
+        for (const char* name : { "Vowel", "Bell", "Low", "Sub" })
+        {
+            const char* text = emu::describePair(name);
+            expect(text != nullptr && text[0] != '\0', juce::String("no description for ") + name);
+        }
+
+        expect(emu::describePair("Robot") == nullptr);
+        expect(emu::describePair(nullptr) == nullptr);
+    }
+
+    // synth-258~2
+    void textParsing()
+    {
+        beginTest("Shape text files parse 12 values, pairs split on a blank line, bad input is reported");
+
+        auto format = [](const emu::Shape& shape, const char* sep)
+        {
+            std::string text;
+            for (size_t i = 0; i < shape.size(); ++i)
+                text += (i > 0 ? sep : "") + std::to_string(shape[i]);
+            return text;
+        };
+
+        emu::Shape parsed{};
+        expect(emu::parseShape(format(emu::VOWEL_A, " "), parsed).ok());
+        for (size_t i = 0; i < parsed.size(); ++i)
+            expectWithinAbsoluteError(parsed[i], emu::VOWEL_A[i], 1.0e-6f);
+        expect(emu::parseShape(format(emu::BELL_B, ",\n  "), parsed).ok());
+        expectWithinAbsoluteError(parsed[11], emu::BELL_B[11], 1.0e-6f);
+
+        // Errors leave the output alone and point at the offending value
+        auto expectError = [&](const std::string& text, emu::ShapeParseError error, int index)
+        {
+            emu::Shape out = emu::SUB_A;
+            const auto status = emu::parseShape(text, out);
+            expect(status.error == error, juce::String("wrong error for: ") + juce::String(text));
+            expectEquals(status.valueIndex, index);
+            expect(juce::String(status.message()) != "ok");
+            expect(out == emu::SUB_A, "failed parse wrote the output");
+        };
+        expectError("0.9 0.1 0.9 x", emu::ShapeParseError::BadNumber, 3);
+        expectError("0.9 0.1 0.9", emu::ShapeParseError::TooFewValues, 3);
+        expectError(format(emu::VOWEL_A, " ") + " 0.5", emu::ShapeParseError::TooManyValues, 12);
+        expectError("0.9 0.1 1.5 0.2", emu::ShapeParseError::RadiusOutOfRange, 2);
+        expectError("0.9 0.1 1.0 0.2", emu::ShapeParseError::RadiusOutOfRange, 2);
+        expectError("0.9 nan", emu::ShapeParseError::NonFiniteTheta, 1);
+
+        // Pair files
+        emu::Shape a{}, b{};
+        expect(emu::parsePair(format(emu::LOW_A, " ") + "\n\n" + format(emu::LOW_B, " ") + "\n", a, b).ok());
+        expectWithinAbsoluteError(a[0], emu::LOW_A[0], 1.0e-6f);
+        expectWithinAbsoluteError(b[0], emu::LOW_B[0], 1.0e-6f);
+
+        expect(emu::parsePair(format(emu::LOW_A, " "), a, b).error == emu::ShapeParseError::MissingSecondShape);
+        const auto second = emu::parsePair(format(emu::LOW_A, " ") + "\n \n0.9 0.1", a, b);
+        expect(second.error == emu::ShapeParseError::TooFewValues);
+        expectEquals(second.shapeIndex, 1);
+    }
+
+    // synth-259~2
+    void registry()
+    {
+        beginTest("Registry starts with the built-ins, takes runtime pairs, and leaves getPair alone");
+
+        emu::ShapeRegistry registry;
+        expectEquals(registry.size(), emu::SHAPE_PAIRS.size());
+        for (const auto& p : emu::SHAPE_PAIRS)
+        {
+            const auto* entry = registry.get(p.name);
+            expect(entry != nullptr && entry->first == *p.a && entry->second == *p.b, juce::String("missing built-in ") + p.name);
+        }
+        expect(registry.get("Robot") == nullptr);
+
+        emu::Shape custom = emu::LOW_A;
+        custom[0] = 0.5f;
+        registry.add("Robot", custom, emu::BELL_B);
+        expectEquals(registry.size(), emu::SHAPE_PAIRS.size() + 1);
+        const auto* robot = registry.get("Robot");
+        expect(robot != nullptr && robot->first == custom && robot->second == emu::BELL_B);
+
+        // Re-registering a name replaces it, built-ins included; the static table is untouched
+        registry.add("Vowel", emu::SUB_A, emu::SUB_B);
+        expectEquals(registry.size(), emu::SHAPE_PAIRS.size() + 1);
+        expect(registry.get("Vowel")->first == emu::SUB_A);
+        expect(*emu::getPair(0).a == emu::VOWEL_A);
+        expectEquals(juce::String(emu::getPair(99).name), juce::String("Vowel"));
+    }
+
+    // synth-254
+    void sharedRangeCheck()
+    {
+        beginTest("Text parsing and tryLoadShape accept exactly the same pole radii");
+
+        for (float r : { 0.0f, 0.5f, 0.999f, 1.0f, 1.0001f, -0.001f, std::nanf("") })
+        {
+            emu::Shape shape = emu::VOWEL_A;
+            shape[4] = r;
+
+            std::string text;
+            for (size_t i = 0; i < shape.size(); ++i)
+                text += (i > 0 ? " " : "") + std::to_string(shape[i]);
+
+            emu::Shape parsed{};
+            std::array<emu::PolePair, 6> poles{};
+            const bool parsedOk = emu::parseShape(text, parsed).ok();
+            const bool loadedOk = emu::tryLoadShape(shape, poles).ok();
+            expect(parsedOk == loadedOk, "paths disagree on radius " + juce::String(r));
+            expect(loadedOk == emu::isValidPoleRadius(r));
+        }
+    }
+};
+
+static ShapeTests shapeTests;
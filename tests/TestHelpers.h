@@ -0,0 +1,65 @@
+#pragma once
+#include "../plugins/EngineField/Source/dsp/ZPlaneFilter.h"
+#include "../plugins/EngineField/Source/dsp/EMUAuthenticTables.h"
+#include "../plugins/EngineField/Source/dsp/TestSignals.h"
+
+#include <algorithm>
+#include <cmath>
+#include <vector>
+
+namespace enginefield::test
+{
+    inline constexpr double SampleRate = 48000.0;
+    inline constexpr int BlockSize = 512;
+
+    // Vowel pair, prepared and with the morph/intensity/drive/mix smoothers snapped to the given settings
+    inline void prepareVowel(emu::ZPlaneFilter& f, double sampleRate = SampleRate, float morph = 0.5f,
+                             float intensity = emu::AUTHENTIC_INTENSITY, float drive = emu::AUTHENTIC_DRIVE, float mix = 1.0f)
+    {
+        f.prepare(sampleRate, BlockSize);
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_B, "Vowel");
+        f.setMorph(morph);
+        f.setIntensity(intensity);
+        f.setDrive(drive);
+        f.setMix(mix);
+        f.morphSmooth.setCurrentAndTargetValue(morph);
+        f.intensitySmooth.setCurrentAndTargetValue(intensity);
+        f.driveSmooth.setCurrentAndTargetValue(drive);
+        f.mixSmooth.setCurrentAndTargetValue(mix);
+        f.snapMorphPole();
+        f.updateCoeffsBlock(BlockSize);
+    }
+
+    // Block-wise processing the way the plugin drives the filter (one coefficient update per block)
+    inline void processBlocks(emu::ZPlaneFilter& f, std::vector<float>& left, std::vector<float>& right, int blockSize = BlockSize)
+    {
+        const int total = static_cast<int>(left.size());
+        for (int start = 0; start < total; start += blockSize)
+        {
+            const int n = std::min(blockSize, total - start);
+            f.updateCoeffsBlock(n);
+            f.process(left.data() + start, right.data() + start, n);
+        }
+    }
+
+    inline float rms(const std::vector<float>& x, size_t from = 0)
+    {
+        double sum = 0.0;
+        for (size_t i = from; i < x.size(); ++i) sum += static_cast<double>(x[i]) * x[i];
+        return static_cast<float>(std::sqrt(sum / static_cast<double>(std::max<size_t>(1, x.size() - from))));
+    }
+
+    inline float maxAbsDiff(const std::vector<float>& a, const std::vector<float>& b, size_t from = 0)
+    {
+        float d = 0.0f;
+        for (size_t i = from; i < std::min(a.size(), b.size()); ++i) d = std::max(d, std::abs(a[i] - b[i]));
+        return d;
+    }
+
+    inline float peak(const std::vector<float>& x, size_t from = 0)
+    {
+        float p = 0.0f;
+        for (size_t i = from; i < x.size(); ++i) p = std::max(p, std::abs(x[i]));
+        return p;
+    }
+}
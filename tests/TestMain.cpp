@@ -0,0 +1,18 @@
+#include <juce_core/juce_core.h>
+#include <juce_gui_basics/juce_gui_basics.h>
+
+// Runs every juce::UnitTest registered under the "EngineField" category; non-zero exit on any failure
+int main()
+{
+    juce::ScopedJuceInitialiser_GUI juceInit; // APVTS / parameter listeners expect a message manager
+
+    juce::UnitTestRunner runner;
+    runner.setAssertOnFailure(false);
+    runner.runTestsInCategory("EngineField");
+
+    int failures = 0;
+    for (int i = 0; i < runner.getNumResults(); ++i)
+        failures += runner.getResult(i)->failures;
+
+    return failures > 0 ? 1 : 0;
+}
@@ -0,0 +1,78 @@
+#include "../plugins/EngineField/Source/dsp/TestSignals.h"
+
+#include <juce_core/juce_core.h>
+#include <cmath>
+#include <vector>
+
+class TestSignalsTests : public juce::UnitTest
+{
+public:
+    TestSignalsTests() : juce::UnitTest("TestSignals", "EngineField") {}
+
+    void runTest() override
+    {
+        generators();
+    }
+
+private:
+    static constexpr float SampleRate = 48000.0f;
+
+    static double rms(const std::vector<float>& x, size_t begin = 0, size_t end = 0)
+    {
+        if (end == 0) end = x.size();
+        double sum = 0.0;
+        for (size_t n = begin; n < end; ++n) sum += static_cast<double>(x[n]) * x[n];
+        return std::sqrt(sum / static_cast<double>(end - begin));
+    }
+
+    // Frequency estimate from rising zero crossings over [begin, end)
+    static double zeroCrossingHz(const std::vector<float>& x, size_t begin, size_t end)
+    {
+        int crossings = 0;
+        for (size_t n = begin + 1; n < end; ++n)
+            if (x[n - 1] < 0.0f && x[n] >= 0.0f) ++crossings;
+        return static_cast<double>(crossings) * SampleRate / static_cast<double>(end - begin);
+    }
+
+    // synth-244
+    void generators()
+    {
+        beginTest("Generators are deterministic per seed with the expected level and spectrum");
+
+        namespace ts = emu::testsignals;
+        constexpr size_t n = 1 << 16;
+
+        expect(ts::whiteNoise(42, n) == ts::whiteNoise(42, n));
+        expect(ts::pinkNoise(42, n) == ts::pinkNoise(42, n));
+        expect(ts::whiteNoise(42, n) != ts::whiteNoise(43, n));
+
+        // White: uniform in [-1, 1), RMS 1/sqrt(3). Differencing doubles the power of white noise,
+        // but removes most of pink noise's low-frequency weight
+        const auto white = ts::whiteNoise(1, n);
+        const auto pink = ts::pinkNoise(1, n);
+        expectWithinAbsoluteError(rms(white), 1.0 / std::sqrt(3.0), 0.01);
+
+        auto diffRatio = [](const std::vector<float>& x)
+        {
+            std::vector<float> d(x.size() - 1);
+            for (size_t i = 0; i < d.size(); ++i) d[i] = x[i + 1] - x[i];
+            return rms(d) / rms(x);
+        };
+        expectWithinAbsoluteError(diffRatio(white), std::sqrt(2.0), 0.05);
+        expectLessThan(diffRatio(pink), 0.5 * diffRatio(white), "pink noise is not low-frequency weighted");
+
+        // Sine: unit amplitude, crossing at its own frequency
+        const auto tone = ts::sine(1000.0f, 48000, SampleRate);
+        expectWithinAbsoluteError(rms(tone), 1.0 / std::sqrt(2.0), 1.0e-3);
+        expectWithinAbsoluteError(zeroCrossingHz(tone, 0, tone.size()), 1000.0, 2.0);
+
+        // Log chirp: starts near startHz, ends near endHz, geometric mean at the midpoint
+        const auto chirp = ts::logChirp(100.0f, 10000.0f, 96000, SampleRate);
+        expectWithinAbsoluteError(zeroCrossingHz(chirp, 0, 4800), 100.0 * std::pow(100.0, 0.025), 25.0);
+        expectWithinAbsoluteError(zeroCrossingHz(chirp, 91200, 96000), 10000.0 / std::pow(100.0, 0.025), 400.0);
+        expectWithinAbsoluteError(zeroCrossingHz(chirp, 45600, 50400), 1000.0, 60.0);
+        expectWithinAbsoluteError(rms(chirp), 1.0 / std::sqrt(2.0), 0.01);
+    }
+};
+
+static TestSignalsTests testSignalsTests;
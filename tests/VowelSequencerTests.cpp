@@ -0,0 +1,58 @@
+#include "../plugins/EngineField/Source/dsp/VowelSequencer.h"
+
+#include <juce_core/juce_core.h>
+#include <vector>
+
+class VowelSequencerTests : public juce::UnitTest
+{
+public:
+    VowelSequencerTests() : juce::UnitTest("VowelSequencer", "EngineField") {}
+
+    void runTest() override
+    {
+        cyclesThroughPositions();
+    }
+
+private:
+    // synth-214
+    void cyclesThroughPositions()
+    {
+        beginTest("Vowel sequence lands on each position in turn, wraps, and glides in between");
+
+        constexpr double sampleRate = 48000.0;
+        constexpr int blockSize = 480;     // 10 steps/s -> a tenth of a step per block
+        const float positions[] = { 0.1f, 0.9f, 0.4f };
+
+        emu::VowelSequencer seq;
+        seq.prepare(sampleRate);
+        expect(! seq.isActive());
+        seq.setSequence(positions, 3, 10.0f);
+        expect(seq.isActive());
+
+        std::vector<float> morph;
+        for (int b = 0; b < 70; ++b)
+            morph.push_back(seq.advance(blockSize, 120.0));
+
+        // Block starts on whole steps hit the positions exactly, two full cycles
+        for (int step = 0; step < 7; ++step)
+            expectWithinAbsoluteError(morph[static_cast<size_t>(step * 10)], positions[step % 3], 1.0e-4f);
+
+        // Between steps the morph moves monotonically from one position to the next
+        for (int b = 1; b < 10; ++b)
+        {
+            expectGreaterThan(morph[static_cast<size_t>(b)], morph[static_cast<size_t>(b - 1)]);
+            expectLessThan(morph[static_cast<size_t>(10 + b)], morph[static_cast<size_t>(10 + b - 1)]);
+        }
+
+        // Tempo sync: one step per beat at 120 BPM is two steps per second
+        seq.setSequence(positions, 3, 1.0f);
+        seq.setTempoSync(true);
+        seq.advance(24000, 120.0);
+        expectWithinAbsoluteError(seq.current(), positions[1], 1.0e-4f);
+
+        seq.setSequence(positions, 0, 1.0f);
+        expect(! seq.isActive());
+    }
+};
+
+static VowelSequencerTests vowelSequencerTests;
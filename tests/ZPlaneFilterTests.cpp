@@ -0,0 +1,2846 @@
+#include "TestHelpers.h"
+#include "../plugins/EngineField/Source/dsp/ShapeRegistry.h"
+
+#include <juce_core/juce_core.h>
+#include <complex>
+#include <limits>
+#include <optional>
+#include <string>
+#include <type_traits>
+
+using namespace enginefield::test;
+
+class ZPlaneFilterTests : public juce::UnitTest
+{
+public:
+    ZPlaneFilterTests() : juce::UnitTest("ZPlaneFilter", "EngineField") {}
+
+    void runTest() override
+    {
+        msModes();
+        dcNyquistGain();
+        perceptualIntensity();
+        blockSizeIndependentSmoothing();
+        coeffUpdateInterval();
+        spreadAlignment();
+        reconfigPolicy();
+        settlingTime();
+        passthroughSections();
+        builder();
+        allpassDecorrelation();
+        remapMode();
+        selfTest();
+        mixEndpointSmoothing();
+        morphBuffer();
+        radiusFloor();
+        shapeName();
+        denormalStrategies();
+        stateGlide();
+        inputGain();
+        dryTap();
+        shapeValidation();
+        morphExtrapolation();
+        crossoverOversampling();
+        morphSmoothness();
+        coeffSkip();
+        combResonator();
+        driveFollowsMix();
+        snapshotFreeze();
+        radiusCombine();
+        phaseDelay();
+        srIntensityComp();
+        tailLength();
+        pipelineStages();
+        zeroFactorPair();
+        inharmonicity();
+        systemPoles();
+        mixbusClip();
+        coeffQuality();
+        crossfade();
+        interleaved();
+        staticFastPath();
+        pairIntensityCurve();
+        sectionCount();
+        tryLoadShape();
+        cascadeMagnitude();
+        wetOnly();
+        frequencyResponse();
+        zeroModes();
+        hpPosition();
+        liveShapeSwap();
+        linearImpulseResponse();
+        limiterTiming();
+        sectionParams();
+        linearPhase();
+        identicalShapes();
+        progressiveReveal();
+        sectionSaturation();
+        oversamplingBypass();
+        dcBlocker();
+        intensityFastPath();
+        oversamplingAliasing();
+        morphOnePole();
+        normalizedResponse();
+        bipolarShapes();
+        audioRateMorph();
+        instanceSeed();
+        morphGainComp();
+        midSideMode();
+        stereoMorph();
+        frequencyShift();
+        formantStretch();
+        flushToZero();
+        sectionCoeffsReference();
+        seedState();
+        nanEvents();
+        rampedMix();
+        rampedTargets();
+        resetCleanRestart();
+        morphGainTable();
+        stereoMorphTargets();
+    }
+
+private:
+    // synth-202
+    void msModes()
+    {
+        beginTest("SideOnly leaves a mono input unchanged and filters a hard-panned one");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setMsMode(emu::MsMode::SideOnly);
+
+        const auto noise = emu::testsignals::whiteNoise(1, 8192);
+        auto l = noise, r = noise;
+        processBlocks(f, l, r);
+        expectLessThan(maxAbsDiff(l, noise), 1.0e-5f, "mono input changed in SideOnly");
+        expectLessThan(maxAbsDiff(r, noise), 1.0e-5f, "mono input changed in SideOnly");
+
+        f.reset();
+        auto pl = noise;
+        std::vector<float> pr(noise.size(), 0.0f);
+        processBlocks(f, pl, pr);
+        expectGreaterThan(maxAbsDiff(pl, noise), 0.1f, "hard-panned input passed unfiltered");
+
+        // The unfiltered component is part of the dry signal, so it follows the dry tap
+        for (auto tap : { emu::DryTap::RawInput, emu::DryTap::PostInputGain })
+        {
+            emu::ZPlaneFilter g;
+            prepareVowel(g);
+            g.setMsMode(emu::MsMode::SideOnly);
+            g.setDryTap(tap);
+            g.setInputGain(2.0f);
+            g.inputGainSmooth.setCurrentAndTargetValue(2.0f);
+
+            auto ml = noise, mr = noise;
+            processBlocks(g, ml, mr);
+            const float expected = (tap == emu::DryTap::RawInput) ? 1.0f : 2.0f;
+            for (size_t n = 0; n < noise.size(); n += 97)
+            {
+                expectWithinAbsoluteError(ml[n], expected * noise[n], 1.0e-5f);
+                expectWithinAbsoluteError(mr[n], expected * noise[n], 1.0e-5f);
+            }
+        }
+    }
+
+    // synth-203
+    void dcNyquistGain()
+    {
+        beginTest("Closed-form DC gain matches the impulse response sum");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+
+        // Brute force: DC gain of a linear copy of the cascade is the sum of its impulse response
+        auto probe = f.cascadeL;
+        for (auto& s : probe.sections) s.setSaturation(0.0f);
+        double sum = 0.0, alt = 0.0;
+        for (int n = 0; n < 1 << 16; ++n)
+        {
+            const double y = probe.process(n == 0 ? 1.0f : 0.0f);
+            sum += y;
+            alt += (n % 2 == 0) ? y : -y;
+        }
+        expectWithinAbsoluteError(f.dcGain(), static_cast<float>(std::abs(sum)), 1.0e-3f * std::max(1.0f, f.dcGain()));
+        expectWithinAbsoluteError(f.nyquistGain(), static_cast<float>(std::abs(alt)), 1.0e-3f * std::max(1.0f, f.nyquistGain()));
+    }
+
+    // synth-204
+    void perceptualIntensity()
+    {
+        beginTest("Perceptual intensity mapping is monotonic and favours the high-resonance region");
+
+        float prev = emu::intensityPerceptual(0.0f);
+        bool monotonic = true;
+        for (int i = 1; i <= 100; ++i)
+        {
+            const float y = emu::intensityPerceptual(static_cast<float>(i) / 100.0f);
+            monotonic = monotonic && y > prev;
+            prev = y;
+        }
+        expect(monotonic, "mapping is not strictly increasing");
+        expectWithinAbsoluteError(emu::intensityPerceptual(0.0f), 0.0f, 1.0e-6f);
+        expectWithinAbsoluteError(emu::intensityPerceptual(1.0f), 1.0f, 1.0e-6f);
+
+        // Knob travel spent above intensity 0.5 exceeds the linear map's half of the range
+        const float knobAtHalf = emu::intensityPerceptualInverse(0.5f);
+        expectLessThan(knobAtHalf, 0.4f, "upper half of the intensity range gets too little travel");
+
+        for (float y : { 0.0f, 0.1f, 0.4f, 0.75f, 1.0f })
+            expectWithinAbsoluteError(emu::intensityPerceptual(emu::intensityPerceptualInverse(y)), y, 1.0e-5f);
+    }
+
+    // synth-205
+    void blockSizeIndependentSmoothing()
+    {
+        beginTest("Sample-count smoothing gives identical output for 64- and 512-sample blocks");
+
+        auto run = [](int blockSize)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.1f);
+            f.setSmoothingSamples(2400);
+            f.setMorph(0.9f);
+            f.setIntensity(0.7f);
+            auto l = emu::testsignals::whiteNoise(7, 8192);
+            auto r = emu::testsignals::whiteNoise(8, 8192);
+            processBlocks(f, l, r, blockSize);
+            return l;
+        };
+
+        expectEquals(maxAbsDiff(run(64), run(512)), 0.0f);
+    }
+
+    // synth-206
+    void coeffUpdateInterval()
+    {
+        beginTest("Coefficient update interval limits recomputes and keeps the output smooth");
+
+        auto run = [](int interval, int& updates)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.0f);
+            f.setCoeffUpdateInterval(interval);
+            auto l = emu::testsignals::sine(220.0f, 48000, static_cast<float>(SampleRate));
+            for (auto& x : l) x *= 0.25f;
+            auto r = l;
+
+            // A buggy host: 16-sample blocks with the morph moving every block
+            updates = 0;
+            float lastTarget = f.cascadeL.sections[0].tb0, lastB0 = f.cascadeL.sections[0].b0;
+            for (int start = 0; start < 48000; start += 16)
+            {
+                f.setMorph(static_cast<float>(start) / 48000.0f);
+                f.updateCoeffsBlock(16);
+                const auto& s = f.cascadeL.sections[0];
+                const float now = interval > 0 ? s.tb0 : s.b0;
+                if (now != (interval > 0 ? lastTarget : lastB0)) ++updates;
+                lastTarget = s.tb0;
+                lastB0 = s.b0;
+                f.process(l.data() + start, r.data() + start, 16);
+            }
+            return l;
+        };
+
+        int everyBlock = 0, limited = 0;
+        const auto ref = run(0, everyBlock);
+        const auto out = run(1024, limited);
+        expectGreaterThan(everyBlock, 1000);
+        expectLessOrEqual(limited, 48000 / 1024 + 1);
+
+        auto maxStep = [](const std::vector<float>& x)
+        {
+            float m = 0.0f;
+            for (size_t i = 1; i < x.size(); ++i) m = std::max(m, std::abs(x[i] - x[i - 1]));
+            return m;
+        };
+        logMessage(juce::String("max sample step: every block ") + juce::String(maxStep(ref)) + ", limited " + juce::String(maxStep(out)));
+        expectLessThan(maxStep(out), 1.25f * maxStep(ref), "rate-limited coefficients introduced steps");
+    }
+
+    // synth-208
+    void spreadAlignment()
+    {
+        beginTest("Spread alignment brings L/R transient arrival within one sample");
+
+        // Arrival time = energy centroid of each channel's impulse response
+        auto arrivalDifference = [](bool align)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, 0.8f, 0.0f);
+            f.setSectionSaturation(0.0f);
+            f.setStereoSpread(1.0f);
+            f.updateCoeffsBlock(BlockSize);
+            f.setSpreadAlign(align); // toggled with a static morph (must still take effect)
+
+            std::vector<float> l(16384, 0.0f), r(16384, 0.0f);
+            l[0] = r[0] = 0.1f;
+            processBlocks(f, l, r);
+
+            auto centroid = [](const std::vector<float>& x)
+            {
+                double num = 0.0, den = 0.0;
+                for (size_t n = 0; n < x.size(); ++n) { num += static_cast<double>(n) * x[n] * x[n]; den += static_cast<double>(x[n]) * x[n]; }
+                return num / den;
+            };
+            return std::abs(centroid(l) - centroid(r));
+        };
+
+        const double unaligned = arrivalDifference(false);
+        const double aligned = arrivalDifference(true);
+        logMessage(juce::String("arrival difference: unaligned ") + juce::String(unaligned) + ", aligned " + juce::String(aligned));
+        expectGreaterThan(unaligned, 2.0, "spread does not produce an arrival difference to correct");
+        expectLessOrEqual(aligned, 1.0, "transients not aligned within one sample");
+    }
+
+    // synth-209
+    void reconfigPolicy()
+    {
+        beginTest("Reconfiguration policy decides whether a second prepare keeps the filter state");
+
+        auto stateAfterReprepare = [](emu::ReconfigPolicy policy)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f);
+            f.setReconfigPolicy(policy);
+            auto l = emu::testsignals::whiteNoise(1, 4096), r = l;
+            processBlocks(f, l, r);
+            f.prepare(SampleRate, BlockSize);
+            const auto s = f.cascadeL.sections[0].state();
+            return std::abs(s.first) + std::abs(s.second);
+        };
+
+        expectEquals(stateAfterReprepare(emu::ReconfigPolicy::Reset), 0.0f, "Reset kept state");
+        expectGreaterThan(stateAfterReprepare(emu::ReconfigPolicy::Preserve), 0.0f, "Preserve cleared state");
+
+        // SoftFade: the wet output restarts from silence and ramps in
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setReconfigPolicy(emu::ReconfigPolicy::SoftFade);
+        f.prepare(SampleRate, BlockSize);
+        std::vector<float> l(BlockSize, 0.5f), r = l;
+        processBlocks(f, l, r);
+        expectLessThan(std::abs(l[0]), 0.05f, "SoftFade did not start from silence");
+    }
+
+    // synth-210
+    void settlingTime()
+    {
+        beginTest("Settling time grows with intensity and leaves the live state untouched");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.2f);
+        const float low = f.settlingTimeMs(static_cast<float>(SampleRate));
+
+        prepareVowel(f, SampleRate, 0.5f, 0.9f);
+        auto l = emu::testsignals::whiteNoise(1, 2048), r = l;
+        processBlocks(f, l, r);
+        const auto before = f.cascadeL.sections[0].state();
+        const float high = f.settlingTimeMs(static_cast<float>(SampleRate));
+        const auto after = f.cascadeL.sections[0].state();
+
+        expectGreaterThan(low, 0.0f);
+        expectGreaterThan(high, low, "higher intensity did not ring longer");
+        expect(before == after, "settlingTimeMs disturbed the cascade state");
+    }
+
+    // synth-211
+    void passthroughSections()
+    {
+        beginTest("Sections below the passthrough radius become identity sections");
+
+        emu::ZPlaneFilter::ShapeArray shape = emu::VOWEL_A;
+        shape[0] = shape[2 * 1] = emu::MIN_POLE_RADIUS; // sections 0 and 1 near the origin
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setShapePair(shape, shape, "Tiny");
+        f.setPassthroughRadius(0.2f);
+        f.updateCoeffsBlock(BlockSize);
+
+        for (int i : { 0, 1 })
+        {
+            const auto& s = f.cascadeL.sections[static_cast<size_t>(i)];
+            expect(s.passthrough, "tiny-radius section still filtering");
+            const auto c = f.sectionCoeffs(0, i);
+            expectEquals(c.b0, 1.0f);
+            expectEquals(c.a1 + c.a2 + c.b1 + c.b2, 0.0f);
+        }
+        expect(! f.cascadeL.sections[2].passthrough, "resonant section bypassed");
+    }
+
+    // synth-212
+    void builder()
+    {
+        beginTest("Builder returns a filter already settled at the configured values");
+
+        auto f = emu::ZPlaneFilterBuilder{}
+                     .shapes(emu::VOWEL_A, emu::VOWEL_B)
+                     .sampleRate(SampleRate)
+                     .morph(0.9f)
+                     .intensity(1.0f)
+                     .drive(0.7f)
+                     .build();
+
+        expectEquals(f.morphSmooth.getCurrentValue(), 0.9f);
+        expectEquals(f.intensitySmooth.getCurrentValue(), 1.0f);
+        expectEquals(f.driveSmooth.getCurrentValue(), 0.7f);
+        expect(! f.morphSmooth.isSmoothing() && ! f.driveSmooth.isSmoothing(), "builder left smoothers ramping");
+
+        // Same coefficients as imperative setup snapped to the same values
+        emu::ZPlaneFilter g;
+        prepareVowel(g, SampleRate, 0.9f, 1.0f, 0.7f);
+        expectEquals(f.sectionCoeffs(0, 0).a1, g.sectionCoeffs(0, 0).a1);
+        expectEquals(f.sectionCoeffs(0, 3).a2, g.sectionCoeffs(0, 3).a2);
+    }
+
+    // synth-213
+    void allpassDecorrelation()
+    {
+        beginTest("All-pass decorrelation lowers L/R correlation without combing the mono sum");
+
+        struct Result { double correlation, monoEnergy; float worstMonoGain; };
+        auto measure = [](float amount)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 0.0f);
+            f.setSectionSaturation(0.0f);
+            f.setSectionAllpassDecorrelation(amount);
+
+            auto l = emu::testsignals::whiteNoise(1, 1 << 15), r = l;
+            processBlocks(f, l, r);
+            double lr = 0.0, ll = 0.0, rr = 0.0, mono = 0.0;
+            for (size_t n = 4096; n < l.size(); ++n)
+            {
+                lr += static_cast<double>(l[n]) * r[n];
+                ll += static_cast<double>(l[n]) * l[n];
+                rr += static_cast<double>(r[n]) * r[n];
+                mono += static_cast<double>(l[n] + r[n]) * (l[n] + r[n]);
+            }
+
+            // Mono-sum gain relative to the channels, per frequency (1 = in phase, 0 = null)
+            f.reset();
+            std::vector<float> hl(8192, 0.0f), hr(8192, 0.0f);
+            hl[0] = hr[0] = 0.01f;
+            processBlocks(f, hl, hr);
+            float worst = 1.0f;
+            for (int k = 0; k < 200; ++k)
+            {
+                const double w = juce::MathConstants<double>::twoPi * 20.0 * std::pow(1000.0, k / 200.0) / SampleRate;
+                std::complex<double> HL, HR;
+                for (size_t n = 0; n < hl.size(); ++n)
+                {
+                    const auto e = std::polar(1.0, -w * static_cast<double>(n));
+                    HL += static_cast<double>(hl[n]) * e;
+                    HR += static_cast<double>(hr[n]) * e;
+                }
+                worst = std::min(worst, static_cast<float>(std::abs(HL + HR) / (std::abs(HL) + std::abs(HR))));
+            }
+            return Result { lr / std::sqrt(ll * rr), mono / (2.0 * (ll + rr)), worst };
+        };
+
+        const auto off = measure(0.0f), half = measure(0.5f), full = measure(1.0f);
+        logMessage(juce::String("correlation ") + juce::String(off.correlation) + " / " + juce::String(half.correlation) + " / " + juce::String(full.correlation)
+                   + ", mono energy " + juce::String(half.monoEnergy) + " / " + juce::String(full.monoEnergy)
+                   + ", worst mono gain " + juce::String(half.worstMonoGain) + " / " + juce::String(full.worstMonoGain));
+
+        expectGreaterThan(off.correlation, 0.999);
+        expectLessThan(half.correlation, off.correlation, "decorrelation did not lower the correlation");
+        expectLessThan(full.correlation, half.correlation, "correlation does not drop with amount");
+        expectGreaterThan(full.monoEnergy, 0.85, "mono sum lost too much energy");
+        expectGreaterThan(full.worstMonoGain, 0.70f, "mono sum has a null deeper than -3 dB");
+        expectGreaterThan(half.worstMonoGain, 0.90f);
+    }
+
+    // synth-215
+    void remapMode()
+    {
+        beginTest("FrequencyScale keeps theta, so pole frequencies double from 48k to 96k");
+
+        auto poleHz = [](double sampleRate, emu::RemapMode mode)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, sampleRate);
+            f.setRemapMode(mode);
+            f.updateCoeffsBlock(BlockSize);
+            std::array<double, emu::ZPlaneFilter::NumSections> hz{};
+            for (size_t i = 0; i < hz.size(); ++i)
+                hz[i] = f.getLastPoles()[i].theta * sampleRate / juce::MathConstants<double>::twoPi;
+            return hz;
+        };
+
+        const auto scaled48 = poleHz(48000.0, emu::RemapMode::FrequencyScale);
+        const auto scaled96 = poleHz(96000.0, emu::RemapMode::FrequencyScale);
+        const auto bilinear48 = poleHz(48000.0, emu::RemapMode::Bilinear);
+        const auto bilinear96 = poleHz(96000.0, emu::RemapMode::Bilinear);
+        for (size_t i = 0; i < scaled48.size(); ++i)
+        {
+            expectWithinAbsoluteError(scaled96[i] / scaled48[i], 2.0, 1.0e-4);
+            expectWithinAbsoluteError(bilinear96[i] / bilinear48[i], 1.0, 0.02, "bilinear remap moved the resonance");
+        }
+    }
+
+    // synth-217
+    void selfTest()
+    {
+        beginTest("Self-test sweep passes on the Vowel pair");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        const auto report = f.selfTest();
+        expectEquals(report.numTested, 125);
+        expect(report.passed(), "self-test reported unstable grid points");
+    }
+
+    // synth-218
+    void mixEndpointSmoothing()
+    {
+        beginTest("Endpoint smoothing flattens the mix gain slopes at the extremes");
+
+        // Wet gain slope across mix = 0.01 .. 0.03, and the mirrored dry gain slope near mix = 1
+        auto slopes = [](float amount)
+        {
+            float wetLo, dryLo, wetHi, dryHi;
+            emu::mixGains(0.01f, amount, wetLo, dryLo);
+            emu::mixGains(0.03f, amount, wetHi, dryHi);
+            const float wetSlope = (wetHi - wetLo) / 0.02f;
+            emu::mixGains(0.97f, amount, wetLo, dryLo);
+            emu::mixGains(0.99f, amount, wetHi, dryHi);
+            return std::make_pair(wetSlope, (dryLo - dryHi) / 0.02f);
+        };
+
+        const auto plain = slopes(0.0f), smooth = slopes(1.0f);
+        expectLessThan(smooth.first, 0.5f * plain.first, "wet gain still rises as steeply as sqrt");
+        expectLessThan(smooth.second, 0.5f * plain.second, "dry gain still falls as steeply as sqrt");
+
+        // The middle of the range stays equal-power
+        float wet, dry;
+        emu::mixGains(0.5f, 1.0f, wet, dry);
+        expectWithinAbsoluteError(wet * wet + dry * dry, 1.0f, 1.0e-5f);
+    }
+
+    // synth-219
+    void morphBuffer()
+    {
+        beginTest("A ramped morph buffer moves the poles gradually across the block");
+
+        constexpr int quarter = 1024;
+        std::vector<float> ramp(4 * quarter);
+        for (size_t n = 0; n < ramp.size(); ++n)
+            ramp[n] = static_cast<float>(n) / static_cast<float>(ramp.size());
+
+        auto thetaAt = [](float morph)
+        {
+            emu::ZPlaneFilter g;
+            prepareVowel(g, SampleRate, morph);
+            return g.getLastPoles()[3].theta;
+        };
+
+        emu::ZPlaneFilter f, still;
+        prepareVowel(f, SampleRate, 0.0f);
+        prepareVowel(still, SampleRate, 0.0f);
+        const std::vector<float> zeros(ramp.size(), 0.0f);
+        auto l = emu::testsignals::whiteNoise(1, ramp.size()), r = l;
+        auto sl = l, sr = r;
+        still.processStereoModulated(sl.data(), sr.data(), static_cast<int>(sl.size()), zeros.data(),
+                                     emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, 1.0f);
+
+        // Same call split into quarters, so the poles can be read part-way through the ramp
+        std::vector<float> theta;
+        for (int q = 0; q < 4; ++q)
+        {
+            const auto offset = static_cast<size_t>(q * quarter);
+            f.processStereoModulated(l.data() + offset, r.data() + offset, quarter, ramp.data() + offset,
+                                     emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, 1.0f);
+            theta.push_back(f.getLastPoles()[3].theta);
+        }
+
+        // The first coefficient grid starts at morph 0, so the output matches a constant-morph run there
+        float startDiff = 0.0f;
+        for (int n = 0; n < 32; ++n)
+            startDiff = std::max(startDiff, std::abs(l[static_cast<size_t>(n)] - sl[static_cast<size_t>(n)]));
+        expectLessThan(startDiff, 1.0e-6f);
+
+        // Each quarter lands on the pole of the morph value at its last coefficient grid, in order
+        const float first = thetaAt(0.0f), last = thetaAt(1.0f);
+        for (int q = 0; q < 4; ++q)
+        {
+            const float expected = thetaAt(ramp[static_cast<size_t>((q + 1) * quarter - 32)]);
+            expectWithinAbsoluteError(theta[static_cast<size_t>(q)], expected, 1.0e-5f);
+            expectGreaterThan((theta[static_cast<size_t>(q)] - first) / (last - first), 0.25f * static_cast<float>(q), "poles did not move with the buffer");
+        }
+    }
+
+    // synth-220
+    void radiusFloor()
+    {
+        beginTest("Radius floor keeps weak shapes resonant");
+
+        emu::ZPlaneFilter::ShapeArray weak = emu::VOWEL_A;
+        for (size_t i = 0; i < weak.size(); i += 2)
+            weak[i] = 0.1f;
+
+        // Peak-to-trough ratio (dB) of the left cascade's magnitude response
+        auto contrastDb = [](const emu::ZPlaneFilter& f)
+        {
+            float lo = 1.0e9f, hi = 0.0f;
+            for (int k = 1; k < 512; ++k)
+            {
+                const float g = f.cascadeL.magnitude(juce::MathConstants<float>::pi * static_cast<float>(k) / 512.0f);
+                lo = std::min(lo, g);
+                hi = std::max(hi, g);
+            }
+            return 20.0f * std::log10(hi / lo);
+        };
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.0f);
+        f.setShapePair(weak, weak, "Weak");
+        f.updateCoeffsBlock(BlockSize);
+        const float flat = contrastDb(f);
+
+        f.setRadiusFloor(0.9f);
+        f.updateCoeffsBlock(BlockSize);
+        for (const auto& p : f.getLastPoles())
+            expectGreaterOrEqual(p.r, 0.9f);
+        expectGreaterThan(contrastDb(f), flat + 12.0f, "floored response is still flat");
+    }
+
+    // synth-221
+    void shapeName()
+    {
+        beginTest("Active shape name follows the loaded pair");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        expectEquals(juce::String(f.currentShapeName()), juce::String("Vowel"));
+
+        f.setShapePair(emu::getPair(1));
+        expectEquals(juce::String(f.currentShapeName()), juce::String("Bell"));
+
+        // The name is copied: it survives the registry that owned the string
+        {
+            emu::ShapeRegistry registry;
+            registry.add("Robot", emu::LOW_A, emu::BELL_B);
+            const auto it = registry.pairs.find("Robot");
+            f.setShapePair(it->second.first, it->second.second, it->first.c_str());
+        }
+        expectEquals(juce::String(f.currentShapeName()), juce::String("Robot"));
+
+        const std::string longName(100, 'x');
+        f.setShapePair(emu::LOW_A, emu::LOW_B, longName.c_str());
+        expectEquals(juce::String(f.currentShapeName()), juce::String(longName.substr(0, emu::ZPlaneFilter::MaxShapeNameLength)));
+        f.setShapePair(emu::LOW_A, emu::LOW_B, nullptr);
+        expectEquals(juce::String(f.currentShapeName()), juce::String("Custom"));
+    }
+
+    // synth-222
+    void denormalStrategies()
+    {
+        for (auto strategy : { emu::DenormalStrategy::Flush, emu::DenormalStrategy::DcOffset })
+        {
+            beginTest(strategy == emu::DenormalStrategy::Flush ? "Flush strategy keeps biquad state normal in silence"
+                                                               : "DC offset strategy keeps biquad state normal in silence");
+
+            emu::ZPlaneFilter f;
+            prepareVowel(f);
+            f.setDenormalStrategy(strategy);
+
+            auto l = emu::testsignals::whiteNoise(1, 4096), r = l;
+            processBlocks(f, l, r);
+            std::vector<float> silenceL(static_cast<size_t>(10.0 * SampleRate), 0.0f), silenceR = silenceL;
+            processBlocks(f, silenceL, silenceR);
+
+            int subnormal = 0;
+            for (const auto* cas : { &f.cascadeL, &f.cascadeR })
+                for (const auto& s : cas->sections)
+                {
+                    const auto [z1, z2] = s.state();
+                    subnormal += (std::fpclassify(z1) == FP_SUBNORMAL) + (std::fpclassify(z2) == FP_SUBNORMAL);
+                }
+            expectEquals(subnormal, 0);
+        }
+    }
+
+    // synth-223
+    void stateGlide()
+    {
+        beginTest("State glide interpolates the settings and lands on the target");
+
+        const emu::FilterState target { 0.8f, 0.9f, 0.5f, 0.4f };
+        for (float morphSmoothingMs : { 0.0f, 50.0f })
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.2f, 0.3f, 0.1f);
+            f.setSectionSaturation(0.0f);
+            f.setMorphSmoothingMs(morphSmoothingMs);
+            f.morphToState(target, 100.0f); // 4800 samples
+
+            for (int b = 0; b < 5; ++b)
+                f.updateCoeffsBlock(480);
+            const auto half = f.getState();
+            expect(f.isGliding());
+            expectWithinAbsoluteError(half.morph, 0.5f, 1.0e-5f);
+            expectWithinAbsoluteError(half.intensity, 0.6f, 1.0e-5f);
+            expectWithinAbsoluteError(half.drive, 0.3f, 1.0e-5f);
+            expectWithinAbsoluteError(half.saturation, 0.2f, 1.0e-5f);
+
+            for (int b = 0; b < 5; ++b)
+                f.updateCoeffsBlock(480);
+            const auto end = f.getState();
+            expect(! f.isGliding());
+            expectEquals(end.morph, target.morph);
+            expectEquals(end.intensity, target.intensity);
+            expectEquals(end.drive, target.drive);
+            expectEquals(end.saturation, target.saturation);
+
+            // The coefficients follow the glide, not a lagging morph smoother
+            emu::ZPlaneFilter g;
+            prepareVowel(g, SampleRate, target.morph, target.intensity, target.drive);
+            for (size_t i = 0; i < static_cast<size_t>(emu::ZPlaneFilter::NumSections); ++i)
+                expectWithinAbsoluteError(f.sectionCoeffs(0, i).a1, g.sectionCoeffs(0, i).a1, 1.0e-6f);
+        }
+    }
+
+    // synth-224
+    void inputGain()
+    {
+        beginTest("Input gain scales the signal entering the filter and the dry tap");
+
+        // Low drive, no section saturation and a quiet input keep the whole path linear
+        auto run = [](float trim, float mix)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 0.0f, mix);
+            f.setSectionSaturation(0.0f);
+            f.setInputGain(trim);
+            f.inputGainSmooth.setCurrentAndTargetValue(trim);
+
+            auto l = emu::testsignals::whiteNoise(1, 4096);
+            for (auto& x : l) x *= 1.0e-3f;
+            auto r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        const auto unity = run(1.0f, 1.0f), boosted = run(2.0f, 1.0f);
+        double num = 0.0, den = 0.0;
+        for (size_t n = 0; n < unity.size(); ++n)
+        {
+            num += static_cast<double>(boosted[n]) * unity[n];
+            den += static_cast<double>(unity[n]) * unity[n];
+        }
+        expectWithinAbsoluteError(num / den, 2.0, 0.01, "wet path did not see the trimmed input");
+
+        const auto dryUnity = run(1.0f, 0.0f), dryBoosted = run(2.0f, 0.0f);
+        for (size_t n = 0; n < dryUnity.size(); n += 97)
+            expectWithinAbsoluteError(dryBoosted[n], 2.0f * dryUnity[n], 1.0e-7f);
+    }
+
+    // synth-225
+    void dryTap()
+    {
+        beginTest("Dry tap point decides whether the dry path carries the input trim");
+
+        auto run = [](emu::DryTap tap, float mix)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, mix);
+            f.setDryTap(tap);
+            f.setInputGain(2.0f);
+            f.inputGainSmooth.setCurrentAndTargetValue(2.0f);
+
+            auto l = emu::testsignals::whiteNoise(1, 2048), r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        const auto input = emu::testsignals::whiteNoise(1, 2048);
+        const auto raw = run(emu::DryTap::RawInput, 0.0f), trimmed = run(emu::DryTap::PostInputGain, 0.0f);
+        for (size_t n = 0; n < input.size(); n += 97)
+        {
+            expectWithinAbsoluteError(raw[n], input[n], 1.0e-6f);
+            expectWithinAbsoluteError(trimmed[n], 2.0f * input[n], 1.0e-6f);
+        }
+
+        expectGreaterThan(maxAbsDiff(run(emu::DryTap::RawInput, 0.5f), run(emu::DryTap::PostInputGain, 0.5f)), 0.1f,
+                          "tap point did not change a 50% mix");
+    }
+
+    // synth-226
+    void shapeValidation()
+    {
+        beginTest("Shape validation flags a 0.999 pole at high intensity at 44.1 kHz");
+
+        auto shape = emu::VOWEL_A;
+        expect(emu::validateShapeForSr(shape, 44100.0, 0.0f).ok(), "stock shape flagged at zero intensity");
+
+        shape[2 * 3] = 0.999f;
+        const auto result = emu::validateShapeForSr(shape, 44100.0, 1.0f);
+        expect(! result.ok());
+        const auto flagged = result.unsafeIndices.begin();
+        expect(std::find(flagged, flagged + result.numUnsafe, 3) != flagged + result.numUnsafe, "0.999 pole not flagged");
+    }
+
+    // synth-227
+    void morphExtrapolation()
+    {
+        beginTest("Extrapolate mode carries morph 1.2 past shape B, Clamp stops at B");
+
+        auto polesAt = [](float morph, emu::MorphClamp mode)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f);
+            f.setMorphClamp(mode);
+            f.setMorph(morph);
+            for (int b = 0; b < 8; ++b) // let the 20 ms morph ramp finish
+                f.updateCoeffsBlock(BlockSize);
+            return f.getLastPoles();
+        };
+
+        const auto a = polesAt(0.0f, emu::MorphClamp::Clamp);
+        const auto b = polesAt(1.0f, emu::MorphClamp::Clamp);
+        const auto clamped = polesAt(1.2f, emu::MorphClamp::Clamp);
+        const auto extended = polesAt(1.2f, emu::MorphClamp::Extrapolate);
+        for (size_t i = 0; i < b.size(); ++i)
+        {
+            expectEquals(clamped[i].theta, b[i].theta);
+            expectGreaterThan((extended[i].theta - b[i].theta) * (b[i].theta - a[i].theta), 0.0f,
+                              "extrapolated pole did not continue past shape B");
+            expectLessOrEqual(extended[i].r, emu::MAX_POLE_RADIUS);
+            expectGreaterOrEqual(extended[i].theta, 0.0f);
+        }
+    }
+
+    // synth-228
+    void crossoverOversampling()
+    {
+        beginTest("Crossover oversampling drives the low band at the base rate and cuts high-band aliasing cheaply");
+
+        constexpr float crossoverHz = 4000.0f;
+        auto makeFilter = [](int factor, emu::OversamplingMode mode)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f);
+            f.setSectionSaturation(0.0f);
+            f.setOversampling(factor);
+            f.setOversamplingMode(mode, crossoverHz);
+            return f;
+        };
+
+        // Low band: a 100 Hz tone goes through the base-rate drive. The crossover output matches the
+        // plain 1x filter fed the Linkwitz-Riley all-pass of the input, shifted by the latency
+        {
+            auto hybrid = makeFilter(2, emu::OversamplingMode::Crossover);
+            auto base = makeFilter(1, emu::OversamplingMode::FullBand);
+            const int latency = hybrid.latencySamples();
+
+            auto l = emu::testsignals::sine(100.0f, 9600, static_cast<float>(SampleRate));
+            for (auto& x : l) x *= 0.8f;
+            auto r = l;
+
+            emu::CrossoverLR4 xo;
+            xo.setCutoff(crossoverHz, SampleRate);
+            auto refL = l;
+            for (auto& x : refL)
+            {
+                float lo, hi;
+                xo.split(x, lo, hi);
+                x = lo + hi;
+            }
+            auto refR = refL;
+
+            processBlocks(hybrid, l, r);
+            processBlocks(base, refL, refR);
+            float diff = 0.0f;
+            for (size_t n = 4800; n + static_cast<size_t>(latency) < l.size(); ++n)
+                diff = std::max(diff, std::abs(l[n + static_cast<size_t>(latency)] - refL[n]));
+            expectLessThan(diff, 1.0e-3f, "low band did not take the base-rate path");
+        }
+
+        // High band: a 9 kHz tone aliases far less than at the base rate (same measure as oversamplingAliasing)
+        auto run = [](emu::ZPlaneFilter& f)
+        {
+            auto l = emu::testsignals::sine(9000.0f, 14400, static_cast<float>(SampleRate));
+            for (auto& x : l) x *= 0.8f;
+            auto r = l;
+            processBlocks(f, l, r);
+
+            auto energyAt = [&l](double hz)
+            {
+                const double w = juce::MathConstants<double>::twoPi * hz / SampleRate;
+                std::complex<double> sum;
+                for (size_t n = l.size() - 4800; n < l.size(); ++n)
+                    sum += static_cast<double>(l[n]) * std::polar(1.0, -w * static_cast<double>(n));
+                return std::norm(sum);
+            };
+            double aliased = 0.0;
+            for (double hz = 12000.0; hz < 24000.0; hz += 10.0)
+                aliased += energyAt(hz);
+            return aliased / energyAt(9000.0);
+        };
+
+        auto base = makeFilter(1, emu::OversamplingMode::FullBand);
+        auto full = makeFilter(2, emu::OversamplingMode::FullBand);
+        auto hybrid = makeFilter(2, emu::OversamplingMode::Crossover);
+        const double baseAlias = run(base), fullAlias = run(full), hybridAlias = run(hybrid);
+        logMessage("Alias/fundamental energy above 12 kHz: 1x " + juce::String(baseAlias) + ", full "
+                   + juce::String(fullAlias) + ", crossover " + juce::String(hybridAlias));
+        expectLessThan(hybridAlias, 0.25 * baseAlias, "crossover mode did not reduce high-band aliasing");
+
+        // Cost: only the drive runs at the oversampled rate (the cascade stays at the base rate)
+        expectEquals(base.oversampledOps, static_cast<uint64_t>(0));
+        expectGreaterThan(hybrid.oversampledOps, static_cast<uint64_t>(0));
+        expectLessThan(hybrid.oversampledOps * static_cast<uint64_t>(4), full.oversampledOps);
+    }
+
+    // synth-231
+    void morphSmoothness()
+    {
+        beginTest("Geodesic radius interpolation morphs the Vowel pair more smoothly than linear");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setGeodesicRadius(true);
+        const float geodesic = f.morphSmoothness(static_cast<float>(SampleRate), 32);
+        f.setGeodesicRadius(false);
+        const float linear = f.morphSmoothness(static_cast<float>(SampleRate), 32);
+        logMessage(juce::String("max step: geodesic ") + juce::String(geodesic) + " dB, linear " + juce::String(linear) + " dB");
+
+        expectGreaterThan(geodesic, 0.0f);
+        expectLessThan(geodesic, linear);
+
+        // Finer sweeps take smaller steps
+        expectLessThan(f.morphSmoothness(static_cast<float>(SampleRate), 64), linear);
+    }
+
+    // synth-233
+    void coeffSkip()
+    {
+        beginTest("Velocity-aware skip holds coefficients for a near-static morph, not a fast one");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setCoeffSkipThreshold(0.5f); // morph units per second
+
+        // ~0.01/s creep: skipped until the drift reaches MaxStaleDelta, then one recompute
+        float morph = 0.5f;
+        int recomputes = 0;
+        for (int b = 0; b < 100; ++b)
+        {
+            morph += 1.0e-4f;
+            f.morphSmooth.setCurrentAndTargetValue(morph);
+            f.updateCoeffsBlock(BlockSize);
+            recomputes += (f.lastMorph == morph);
+        }
+        expectGreaterThan(f.skippedCoeffUpdates, 90);
+        expectLessOrEqual(recomputes, 2);
+
+        // ~1.9/s sweep: every call recomputes
+        const int skippedBefore = f.skippedCoeffUpdates;
+        for (int b = 0; b < 20; ++b)
+        {
+            morph -= 0.02f;
+            f.morphSmooth.setCurrentAndTargetValue(morph);
+            f.updateCoeffsBlock(BlockSize);
+            expectEquals(f.lastMorph, morph);
+        }
+        expectEquals(f.skippedCoeffUpdates, skippedBefore);
+    }
+
+    // synth-235
+    void combResonator()
+    {
+        beginTest("Comb resonances sit at integer multiples of the tuned frequency");
+
+        constexpr float combHz = 440.0f; // fractional delay (109.09 samples at 48 kHz)
+        auto impulseResponse = [](float feedback)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 0.0f);
+            f.setSectionSaturation(0.0f);
+            f.setComb(combHz, feedback);
+            f.reset();
+            std::vector<float> l(16384, 0.0f);
+            l[0] = 0.01f;
+            auto r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        auto magnitudeAt = [](const std::vector<float>& h, double hz)
+        {
+            const double w = juce::MathConstants<double>::twoPi * hz / SampleRate;
+            std::complex<double> sum;
+            for (size_t n = 0; n < h.size(); ++n)
+                sum += static_cast<double>(h[n]) * std::polar(1.0, -w * static_cast<double>(n));
+            return std::abs(sum);
+        };
+
+        // Comb gain = combed / plain response, so the formant shape cancels out
+        const auto plain = impulseResponse(0.0f), combed = impulseResponse(0.9f);
+        for (int k = 1; k <= 5; ++k)
+        {
+            const double peak = magnitudeAt(combed, static_cast<float>(k) * combHz) / magnitudeAt(plain, static_cast<float>(k) * combHz);
+            const double notch = magnitudeAt(combed, (k + 0.5) * combHz) / magnitudeAt(plain, (k + 0.5) * combHz);
+            expectGreaterThan(peak, 0.7, "no comb peak at a harmonic");
+            expectGreaterThan(peak, 8.0 * notch, "comb peaks not well above the troughs between harmonics");
+        }
+    }
+
+    // synth-236
+    void driveFollowsMix()
+    {
+        beginTest("Drive-follows-mix keeps the wet path nearly linear at low mix");
+
+        // Third-harmonic to fundamental ratio of the wet path, recovered as (out - dryG * in) / wetG
+        constexpr float mix = 0.02f, toneHz = 375.0f; // whole cycles in the analysis window
+        auto wetHarmonicRatio = [&](bool follow)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f, mix);
+            f.setSectionSaturation(0.0f);
+            f.setDriveFollowsMix(follow);
+
+            const auto in = emu::testsignals::sine(toneHz, 16384, static_cast<float>(SampleRate));
+            auto l = in;
+            for (auto& x : l) x *= 0.8f;
+            auto r = l;
+            processBlocks(f, l, r);
+
+            float wetG, dryG;
+            emu::mixGains(mix, 0.0f, wetG, dryG);
+            auto magnitudeAt = [&](double hz)
+            {
+                const double w = juce::MathConstants<double>::twoPi * hz / SampleRate;
+                std::complex<double> sum;
+                for (size_t n = 8192; n < l.size(); ++n)
+                {
+                    const double wet = (l[n] - dryG * 0.8f * in[n]) / wetG;
+                    sum += wet * std::polar(1.0, -w * static_cast<double>(n));
+                }
+                return std::abs(sum);
+            };
+            return magnitudeAt(3.0 * toneHz) / magnitudeAt(toneHz);
+        };
+
+        const double driven = wetHarmonicRatio(false), following = wetHarmonicRatio(true);
+        logMessage(juce::String("H3/H1: drive always on ") + juce::String(driven) + ", following mix " + juce::String(following));
+        expectGreaterThan(driven, 1.0e-3, "full drive should clearly distort a 0.8 sine");
+        expectLessThan(following, 0.1 * driven, "drive still distorting the wet path at low mix");
+    }
+
+    // synth-237
+    void snapshotFreeze()
+    {
+        beginTest("Active snapshot holds the response while morph moves underneath");
+
+        auto frozen = [](float laterMorph, bool keepSnapshot)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.2f);
+            f.captureSnapshot();
+            f.setUseSnapshot(true);
+
+            f.setMorph(laterMorph);
+            f.morphSmooth.setCurrentAndTargetValue(laterMorph);
+            if (! keepSnapshot)
+                f.setUseSnapshot(false);
+            f.updateCoeffsBlock(BlockSize);
+
+            auto l = emu::testsignals::whiteNoise(1, 4096), r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        const auto still = frozen(0.2f, true);
+        expectLessThan(maxAbsDiff(frozen(0.9f, true), still), 1.0e-7f, "morph changed the frozen output");
+        expectGreaterThan(maxAbsDiff(frozen(0.9f, false), still), 0.01f, "releasing the snapshot did not return to live morph");
+    }
+
+    // synth-239
+    void radiusCombine()
+    {
+        beginTest("Max radius rule takes the larger shape radius at the morph midpoint");
+
+        const emu::PolePair a { 0.90f, 0.3f }, b { 0.98f, 0.5f };
+        expectEquals(emu::interpolatePole(a, b, 0.5f, true, emu::RadiusRule::Max).r, 0.98f);
+        expectEquals(emu::interpolatePole(a, b, 0.5f, true, emu::RadiusRule::Min).r, 0.90f);
+        expectEquals(emu::interpolatePole(a, b, 0.0f, true, emu::RadiusRule::Max).r, a.r);
+        expectEquals(emu::interpolatePole(a, b, 1.0f, true, emu::RadiusRule::Max).r, b.r);
+
+        // In the filter (48 kHz, zero intensity: no remap or boost on top of the interpolation)
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.0f);
+        f.setRadiusCombine(emu::RadiusRule::Max);
+        f.updateCoeffsBlock(BlockSize);
+        for (size_t i = 0; i < static_cast<size_t>(emu::ZPlaneFilter::NumSections); ++i)
+            expectWithinAbsoluteError(f.getLastPoles()[i].r, std::max(emu::VOWEL_A[2 * i], emu::VOWEL_B[2 * i]), 1.0e-6f);
+    }
+
+    // synth-240
+    void phaseDelay()
+    {
+        beginTest("Phase delay is positive and finite, and meets the group delay at DC");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        const auto fs = static_cast<float>(SampleRate);
+        for (float hz : { 50.0f, 200.0f, 1000.0f, 5000.0f })
+        {
+            const float d = f.phaseDelaySamples(hz, fs);
+            expect(std::isfinite(d));
+            expectGreaterThan(d, 0.0f);
+        }
+
+        const float atDc = f.phaseDelaySamples(0.0f, fs);
+        expect(std::isfinite(atDc));
+        expectWithinAbsoluteError(atDc, f.groupDelaySamples(0.0f, fs), 1.0e-3f);
+        expectWithinAbsoluteError(f.phaseDelaySamples(1.0f, fs), atDc, 0.05f * std::abs(atDc), "phase delay jumps near DC");
+    }
+
+    // synth-241
+    void srIntensityComp()
+    {
+        beginTest("Sample-rate intensity compensation keeps resonance bandwidth similar at 48k and 192k");
+
+        // -3 dB bandwidth (Hz) of each section's resonance: -ln(r) * fs / pi, with r = sqrt(a2)
+        auto bandwidths = [](double sampleRate, bool comp)
+        {
+            emu::ZPlaneFilter f;
+            f.setSrIntensityComp(comp);
+            prepareVowel(f, sampleRate, 0.5f, 1.0f);
+            std::array<double, emu::ZPlaneFilter::NumSections> bw{};
+            for (size_t i = 0; i < bw.size(); ++i)
+                bw[i] = -std::log(std::sqrt(static_cast<double>(f.cascadeL.sections[i].a2))) * sampleRate
+                        / juce::MathConstants<double>::pi;
+            return bw;
+        };
+
+        const auto bw48 = bandwidths(48000.0, true);
+        const auto bw192 = bandwidths(192000.0, true);
+        const auto bw192Off = bandwidths(192000.0, false);
+
+        double worstOn = 0.0, worstOff = 0.0;
+        for (size_t i = 0; i < bw48.size(); ++i)
+        {
+            worstOn = std::max(worstOn, std::abs(std::log(bw192[i] / bw48[i])));
+            worstOff = std::max(worstOff, std::abs(std::log(bw192Off[i] / bw48[i])));
+        }
+        expectLessThan(worstOn, std::log(1.1), "compensated bandwidth drifts more than 10% at 192k");
+        expectGreaterThan(worstOff, std::log(2.0), "uncompensated 192k poles should pile up at the radius clamp");
+
+        // At the reference rate the switch is a no-op
+        const auto bw48Off = bandwidths(48000.0, false);
+        for (size_t i = 0; i < bw48.size(); ++i)
+            expectWithinAbsoluteError(bw48[i], bw48Off[i], 1.0e-6);
+    }
+
+    // synth-242
+    void tailLength()
+    {
+        beginTest("Reported tail grows with resonance and scales with the host rate");
+
+        auto tailAt = [](float intensity)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, intensity);
+            return f.tailSamples(static_cast<float>(SampleRate));
+        };
+
+        const auto mild = tailAt(0.1f);
+        const auto resonant = tailAt(1.0f);
+        expectGreaterThan(mild, 0u);
+        expectGreaterThan(resonant, mild, "higher intensity should report a longer tail");
+
+        // Same decay time reported in samples of another rate
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 1.0f);
+        expectWithinAbsoluteError(static_cast<double>(f.tailSamples(2.0f * static_cast<float>(SampleRate))),
+                                  2.0 * resonant, 2.0);
+
+        // The comb's feedback adds its own decay on top
+        f.setComb(200.0f, 0.9f);
+        expectGreaterThan(f.tailSamples(static_cast<float>(SampleRate)), resonant);
+    }
+
+    // synth-243
+    void pipelineStages()
+    {
+        beginTest("Pipeline config bypasses the cascade and the drive stage");
+
+        const auto in = emu::testsignals::sine(375.0f, 16384, static_cast<float>(SampleRate));
+        auto run = [&](uint32_t stages)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f, 1.0f);
+            f.setSectionSaturation(0.0f);
+            f.setPipeline(emu::PipelineConfig{ stages });
+            auto l = in;
+            for (auto& x : l) x *= 0.8f;
+            auto r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        // No cascade (and no input high-pass or makeup gain): the output is exactly the driven input
+        using PC = emu::PipelineConfig;
+        const auto driven = run(PC::All & ~(PC::Cascade | PC::InputHighPass | PC::OutputGain));
+        float worst = 0.0f;
+        for (size_t n = 0; n < in.size(); ++n)
+            worst = std::max(worst, std::abs(driven[n] - std::tanh(0.8f * in[n] * 5.0f)));
+        expectLessThan(worst, 1.0e-5f, "cascade-off output is not the driven input");
+
+        // Drive off: the cascade alone is linear, so no third harmonic
+        auto thirdHarmonicRatio = [](const std::vector<float>& y)
+        {
+            auto magnitudeAt = [&](double hz)
+            {
+                const double w = juce::MathConstants<double>::twoPi * hz / SampleRate;
+                std::complex<double> sum;
+                for (size_t n = 8192; n < y.size(); ++n)
+                    sum += static_cast<double>(y[n]) * std::polar(1.0, -w * static_cast<double>(n));
+                return std::abs(sum);
+            };
+            return magnitudeAt(3.0 * 375.0) / magnitudeAt(375.0);
+        };
+        const double withDrive = thirdHarmonicRatio(run(PC::All));
+        const double withoutDrive = thirdHarmonicRatio(run(PC::All & ~PC::Drive));
+        expectGreaterThan(withDrive, 1.0e-3);
+        expectLessThan(withoutDrive, 1.0e-2 * withDrive, "harmonics remain with the drive stage off");
+    }
+
+    // synth-245
+    void zeroFactorPair()
+    {
+        beginTest("Zero factor follows the morph and softens the resonance toward 1");
+
+        // Same shape on both ends, so only the zero placement changes with morph
+        emu::ZPlaneFilter f;
+        f.prepare(SampleRate, BlockSize);
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_A, "Vowel");
+        f.setIntensity(emu::AUTHENTIC_INTENSITY);
+        f.intensitySmooth.setCurrentAndTargetValue(emu::AUTHENTIC_INTENSITY);
+        f.setZeroFactorPair(0.3f, 0.95f);
+
+        float lastSharpness = std::numeric_limits<float>::max();
+        for (float morph : { 0.0f, 0.25f, 0.5f, 0.75f, 1.0f })
+        {
+            f.setMorph(morph);
+            f.morphSmooth.setCurrentAndTargetValue(morph);
+            f.snapMorphPole();
+            f.updateCoeffsBlock(BlockSize);
+
+            // Effective zero factor of the first section: zero radius / pole radius
+            const auto& s = f.cascadeL.sections[0];
+            const float zeroFactor = std::sqrt(s.b2 / s.b0) / std::sqrt(s.a2);
+            expectWithinAbsoluteError(zeroFactor, 0.3f + morph * 0.65f, 1.0e-3f);
+
+            // Peak-to-floor ratio of the cascade drops as the zeros close in on the poles
+            float peak = 0.0f, floor = std::numeric_limits<float>::max();
+            for (float hz = 50.0f; hz < 20000.0f; hz *= 1.05f)
+            {
+                const float m = f.cascadeL.magnitudeAt(hz, static_cast<float>(SampleRate));
+                peak = std::max(peak, m);
+                floor = std::min(floor, m);
+            }
+            const float sharpness = peak / floor;
+            expectLessThan(sharpness, lastSharpness);
+            lastSharpness = sharpness;
+        }
+    }
+
+    // synth-247
+    void inharmonicity()
+    {
+        beginTest("Inharmonicity ratios scale each section's frequency, capped below Nyquist");
+
+        emu::ZPlaneFilter plain, tuned;
+        prepareVowel(plain);
+        prepareVowel(tuned);
+        const std::array<float, emu::ZPlaneFilter::NumSections> ratios { 1.0f, 1.5f, 2.76f, 0.5f, 5.4f, 1000.0f };
+        tuned.setInharmonicity(ratios);
+        tuned.updateCoeffsBlock(BlockSize);
+
+        const auto& base = plain.getLastPoles();
+        const auto& shifted = tuned.getLastPoles();
+        const float pi = juce::MathConstants<float>::pi;
+        for (size_t i = 0; i + 1 < ratios.size(); ++i)
+        {
+            expectWithinAbsoluteError(shifted[i].theta, std::min(base[i].theta * ratios[i], 0.999f * pi), 1.0e-5f);
+            expectEquals(shifted[i].r, base[i].r);
+        }
+        expectWithinAbsoluteError(shifted.back().theta, 0.999f * pi, 1.0e-5f);
+    }
+
+    // synth-248
+    void systemPoles()
+    {
+        beginTest("System poles stay inside the stability bound and match the cascade");
+
+        for (float morph : { 0.0f, 0.3f, 0.7f, 1.0f })
+            for (float intensity : { 0.0f, 0.4f, 1.0f })
+            {
+                emu::ZPlaneFilter f;
+                prepareVowel(f, SampleRate, morph, intensity);
+
+                const auto pairs = f.systemPoles();
+                const auto poles = f.systemPolesComplex();
+                for (size_t i = 0; i < pairs.size(); ++i)
+                {
+                    expectLessOrEqual(pairs[i].r, emu::MAX_POLE_RADIUS);
+                    expectLessThan(std::abs(poles[2 * i]), 1.0f);
+                    expect(poles[2 * i + 1] == std::conj(poles[2 * i]));
+
+                    // The pair is the section's denominator: a2 = r^2
+                    expectWithinAbsoluteError(f.cascadeL.sections[i].a2, pairs[i].r * pairs[i].r, 1.0e-6f);
+                }
+            }
+    }
+
+    // synth-249
+    void mixbusClip()
+    {
+        beginTest("Mix bus clipper bounds loud mixes and leaves quiet ones untouched");
+
+        auto run = [](float clip, float level)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, 0.5f);
+            f.setMixbusClip(clip);
+            auto l = emu::testsignals::whiteNoise(3, 16384);
+            for (auto& x : l) x *= level;
+            auto r = l;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        float loudPeak = 0.0f;
+        for (float x : run(1.0f, 4.0f)) loudPeak = std::max(loudPeak, std::abs(x));
+        expectLessOrEqual(loudPeak, 1.0f, "clipped mix bus exceeded full scale");
+        expectGreaterThan(loudPeak, 0.5f);
+
+        const auto quietClipped = run(1.0f, 0.05f), quietPlain = run(0.0f, 0.05f);
+        expectEquals(maxAbsDiff(quietClipped, quietPlain), 0.0f, "clipper touched a signal below the knee");
+    }
+
+    // synth-250
+    void coeffQuality()
+    {
+        beginTest("Coefficient quality gives large blocks more updates per block than small ones");
+
+        // Coefficient changes seen while a block runs under a moving morph (processed one sample at a time)
+        auto updatesIn = [](int blockSize)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.0f);
+            f.setCoeffQuality(0.5f);
+            f.morphSmooth.reset(SampleRate, 0.1); // still ramping at the end of the largest block
+            f.setMorph(1.0f);
+            f.updateCoeffsBlock(blockSize);
+
+            int changes = 0;
+            float a1 = f.cascadeL.sections[0].a1;
+            for (int n = 0; n < blockSize; ++n)
+            {
+                float l = 0.0f, r = 0.0f;
+                f.process(&l, &r, 1);
+                if (f.cascadeL.sections[0].a1 != a1) ++changes;
+                a1 = f.cascadeL.sections[0].a1;
+            }
+            return changes;
+        };
+
+        emu::ZPlaneFilter f;
+        f.setCoeffQuality(0.5f);
+        expectGreaterThan(f.coeffUpdatesPerBlock(2048), f.coeffUpdatesPerBlock(64));
+
+        const int large = updatesIn(2048), small = updatesIn(64);
+        expectEquals(large, f.coeffUpdatesPerBlock(2048));
+        expectEquals(small, f.coeffUpdatesPerBlock(64));
+        expectGreaterThan(large, small);
+
+        f.setCoeffQuality(0.0f);
+        expectEquals(f.coeffUpdatesPerBlock(2048), 1);
+    }
+
+    // synth-251
+    void crossfade()
+    {
+        beginTest("Crossfade matches filter A at t = 0, filter B at t = 1 and blends equal-power between");
+
+        const auto input = emu::testsignals::whiteNoise(11, 4096);
+        auto makeA = [] { emu::ZPlaneFilter f; prepareVowel(f, SampleRate, 0.2f); return f; };
+        auto makeB = [] { emu::ZPlaneFilter f; prepareVowel(f, SampleRate, 0.9f, 0.8f, 0.6f); return f; };
+
+        auto alone = [&](emu::ZPlaneFilter f)
+        {
+            auto l = input, r = input;
+            f.process(l.data(), r.data(), static_cast<int>(l.size()));
+            return l;
+        };
+        auto blended = [&](float t)
+        {
+            auto a = makeA(), b = makeB();
+            auto l = input, r = input;
+            a.crossfade(b, l.data(), r.data(), static_cast<int>(l.size()), t);
+            return l;
+        };
+
+        const auto outA = alone(makeA()), outB = alone(makeB());
+        expectLessThan(maxAbsDiff(blended(0.0f), outA), 1.0e-6f);
+        expectLessThan(maxAbsDiff(blended(1.0f), outB), 1.0e-6f);
+
+        for (float t : { 0.25f, 0.5f, 0.75f })
+        {
+            const float gA = std::cos(t * juce::MathConstants<float>::halfPi);
+            const float gB = std::sin(t * juce::MathConstants<float>::halfPi);
+            std::vector<float> expected(input.size());
+            for (size_t n = 0; n < input.size(); ++n)
+                expected[n] = gA * outA[n] + gB * outB[n];
+            expectLessThan(maxAbsDiff(blended(t), expected), 1.0e-5f);
+        }
+    }
+
+    // synth-252
+    void interleaved()
+    {
+        beginTest("Interleaved processing matches planar processing of the same channels");
+
+        const auto left = emu::testsignals::whiteNoise(21, 3000), right = emu::testsignals::whiteNoise(22, 3000);
+        emu::ZPlaneFilter planar, packed;
+        prepareVowel(planar);
+        prepareVowel(packed);
+        planar.setStereoSpread(0.3f);
+        packed.setStereoSpread(0.3f);
+        planar.updateCoeffsBlock(BlockSize);
+        packed.updateCoeffsBlock(BlockSize);
+
+        auto l = left, r = right;
+        planar.process(l.data(), r.data(), static_cast<int>(l.size()));
+
+        std::vector<float> buf(2 * left.size());
+        for (size_t n = 0; n < left.size(); ++n)
+        {
+            buf[2 * n] = left[n];
+            buf[2 * n + 1] = right[n];
+        }
+        packed.processInterleaved(buf.data(), static_cast<int>(buf.size()));
+
+        float worst = 0.0f;
+        for (size_t n = 0; n < left.size(); ++n)
+            worst = std::max({ worst, std::abs(buf[2 * n] - l[n]), std::abs(buf[2 * n + 1] - r[n]) });
+        expectEquals(worst, 0.0f);
+    }
+
+    // synth-252~2
+    void staticFastPath()
+    {
+        beginTest("Unchanged morph and intensity skip the recompute, except right after prepare");
+
+        emu::ZPlaneFilter f;
+        f.prepare(SampleRate, BlockSize);
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_B, "Vowel");
+        f.morphSmooth.setCurrentAndTargetValue(0.5f);
+        f.intensitySmooth.setCurrentAndTargetValue(0.4f);
+
+        // A marker in the live coefficients survives only if updateCoeffsBlock returns early
+        constexpr float marker = 123.0f;
+        auto& a1 = f.cascadeL.sections[0].a1;
+
+        a1 = marker;
+        f.updateCoeffsBlock(0.5f, 0.4f, BlockSize);
+        expectNotEquals(a1, marker, "first update after prepare was skipped");
+
+        const auto poles = f.getLastPoles();
+        const float computed = a1;
+        a1 = marker;
+        f.updateCoeffsBlock(0.5f, 0.4f, BlockSize);
+        expectEquals(a1, marker, "unchanged settings recomputed");
+        for (size_t i = 0; i < poles.size(); ++i)
+        {
+            expectEquals(f.getLastPoles()[i].r, poles[i].r);
+            expectEquals(f.getLastPoles()[i].theta, poles[i].theta);
+        }
+
+        a1 = computed;
+        f.morphSmooth.setCurrentAndTargetValue(0.6f);
+        f.updateCoeffsBlock(0.6f, 0.4f, BlockSize);
+        expectNotEquals(f.getLastPoles()[0].theta, poles[0].theta, "changed morph did not recompute");
+    }
+
+    // synth-253
+    void pairIntensityCurve()
+    {
+        beginTest("Bell and Sub pairs apply their own intensity curves to the radius boost");
+
+        const auto& bell = emu::SHAPE_PAIRS[1];
+        const auto& sub = emu::SHAPE_PAIRS[3];
+        expect(juce::String(bell.name) == "Bell" && juce::String(sub.name) == "Sub");
+
+        // Loading a pair installs its curve
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        f.setShapePair(bell);
+        expectEquals(f.intensityCurve, bell.intensityCurve);
+        f.setShapePair(sub);
+        expectEquals(f.intensityCurve, sub.intensityCurve);
+
+        // Bell's radii already sit at the hardware clamp, so the boost is measured on the (unclamped)
+        // Vowel poles under each pair's curve: largest r(intensity) / r(0) across the sections
+        auto boostUnder = [](float curve, float intensity)
+        {
+            auto polesAt = [curve](float i)
+            {
+                emu::ZPlaneFilter g;
+                g.setIntensityCurve(curve);
+                prepareVowel(g, SampleRate, 0.5f, i);
+                return g.getLastPoles();
+            };
+            const auto base = polesAt(0.0f), boosted = polesAt(intensity);
+            float boost = 0.0f;
+            for (size_t i = 0; i < base.size(); ++i)
+                if (boosted[i].r < emu::MAX_POLE_RADIUS)
+                    boost = std::max(boost, boosted[i].r / base[i].r);
+            return boost;
+        };
+
+        constexpr float intensity = 0.5f;
+        const float bellBoost = boostUnder(bell.intensityCurve, intensity);
+        const float subBoost = boostUnder(sub.intensityCurve, intensity);
+        expectWithinAbsoluteError(bellBoost, 1.0f + 0.06f * std::pow(intensity, bell.intensityCurve), 1.0e-4f);
+        expectWithinAbsoluteError(subBoost, 1.0f + 0.06f * std::pow(intensity, sub.intensityCurve), 1.0e-4f);
+
+        // Bell's curve holds back the boost at half intensity, Sub's brings it in early
+        expectGreaterThan(subBoost, bellBoost + 0.01f);
+    }
+
+    // synth-253~2
+    void sectionCount()
+    {
+        beginTest("Cascades of other lengths build, load 2N-value shapes and roll off more steeply");
+
+        static_assert(std::is_same_v<emu::ZPlaneFilter, emu::ZPlaneFilterN<6>>);
+        static_assert(emu::ZPlaneFilterN<8>::NumSections == 8);
+
+        // Same pole pair in every section: the order-2N rolloff scales with N
+        auto rolloffDb = [this](auto filter)
+        {
+            constexpr size_t n = decltype(filter)::NumSections;
+            std::array<float, 2 * n> shape{};
+            for (size_t i = 0; i < n; ++i) { shape[2 * i] = 0.95f; shape[2 * i + 1] = 0.05f; }
+
+            std::array<emu::PolePair, n> pairs{};
+            emu::loadShape(shape, pairs);
+            for (const auto& p : pairs)
+                expectEquals(p.r, 0.95f);
+
+            filter.prepare(SampleRate, BlockSize);
+            filter.setShapePair(shape, shape, "Flat");
+            filter.morphSmooth.setCurrentAndTargetValue(0.5f);
+            filter.intensitySmooth.setCurrentAndTargetValue(0.0f);
+            filter.updateCoeffsBlock(BlockSize);
+            return filter.cascadeL.magnitudeDbAt(400.0f, static_cast<float>(SampleRate))
+                 - filter.cascadeL.magnitudeDbAt(16000.0f, static_cast<float>(SampleRate));
+        };
+
+        const float four = rolloffDb(emu::ZPlaneFilterN<4>{});
+        const float eight = rolloffDb(emu::ZPlaneFilterN<8>{});
+        expectGreaterThan(four, 0.0f);
+        expectWithinAbsoluteError(eight, 2.0f * four, 0.01f * four);
+    }
+
+    // synth-254
+    void tryLoadShape()
+    {
+        beginTest("tryLoadShape accepts valid shapes and reports the first bad pair without loading");
+
+        std::array<emu::PolePair, emu::ZPlaneFilter::NumSections> out{};
+        const auto good = emu::tryLoadShape(emu::VOWEL_A, out);
+        expect(good.ok());
+        expectEquals(juce::String(good.message()), juce::String("ok"));
+        expectEquals(out[2].r, emu::VOWEL_A[4]);
+        expectEquals(out[2].theta, emu::VOWEL_A[5]);
+
+        const auto loaded = out;
+        auto expectRejected = [&](emu::ZPlaneFilter::ShapeArray shape, emu::ShapeError error, int pair)
+        {
+            const auto status = emu::tryLoadShape(shape, out);
+            expect(! status.ok());
+            expect(status.error == error);
+            expectEquals(status.pairIndex, pair);
+            expect(juce::String(status.message()).isNotEmpty() && juce::String(status.message()) != "ok");
+            for (size_t i = 0; i < out.size(); ++i)
+                expect(out[i].r == loaded[i].r && out[i].theta == loaded[i].theta, "rejected shape was written");
+        };
+
+        auto shape = emu::VOWEL_A;
+        shape[2 * 1] = 1.0f;
+        expectRejected(shape, emu::ShapeError::RadiusOutOfRange, 1);
+
+        shape = emu::VOWEL_A;
+        shape[2 * 4] = -0.1f;
+        expectRejected(shape, emu::ShapeError::RadiusOutOfRange, 4);
+
+        shape = emu::VOWEL_A;
+        shape[2 * 2] = std::numeric_limits<float>::quiet_NaN();
+        expectRejected(shape, emu::ShapeError::RadiusOutOfRange, 2);
+
+        shape = emu::VOWEL_A;
+        shape[2 * 5 + 1] = std::numeric_limits<float>::infinity();
+        expectRejected(shape, emu::ShapeError::NonFiniteTheta, 5);
+    }
+
+    // synth-254~2
+    void cascadeMagnitude()
+    {
+        beginTest("Cascade magnitude peaks at the pole frequency and matches a measured sine gain");
+
+        constexpr float fs = static_cast<float>(SampleRate), poleHz = 1000.0f;
+        emu::BiquadCascade<2> cascade;
+        for (auto& s : cascade.sections)
+        {
+            float a1, a2, b0, b1, b2;
+            emu::poleToBiquad(emu::poleFromFreqQ(poleHz, 20.0f, fs), a1, a2, b0, b1, b2);
+            s.setCoeffs(b0, b1, b2, a1, a2);
+            s.setSaturation(0.0f);
+        }
+
+        const float atPole = cascade.magnitudeAt(poleHz, fs);
+        expectGreaterThan(atPole, 10.0f * cascade.magnitudeAt(100.0f, fs));
+        expectGreaterThan(atPole, 10.0f * cascade.magnitudeAt(10000.0f, fs));
+        expectWithinAbsoluteError(cascade.magnitudeDbAt(poleHz, fs), 20.0f * std::log10(atPole), 1.0e-4f);
+
+        // Steady-state gain of a sine through the cascade agrees with the evaluated H(e^jw)
+        for (float hz : { 300.0f, poleHz, 3000.0f })
+        {
+            auto probe = cascade;
+            probe.reset();
+            const auto tone = emu::testsignals::sine(hz, 48000, fs);
+            float peak = 0.0f;
+            for (size_t n = 0; n < tone.size(); ++n)
+            {
+                const float y = probe.process(tone[n]);
+                if (n >= tone.size() / 2) peak = std::max(peak, std::abs(y));
+            }
+            expectWithinAbsoluteError(peak / cascade.magnitudeAt(hz, fs), 1.0f, 0.01f);
+        }
+    }
+
+    // synth-255
+    void wetOnly()
+    {
+        beginTest("Wet-only fast path matches regular processing at 100% mix");
+
+        emu::ZPlaneFilter regular, fast;
+        prepareVowel(regular, SampleRate, 0.3f, emu::AUTHENTIC_INTENSITY, 0.7f, 1.0f);
+        prepareVowel(fast, SampleRate, 0.3f, emu::AUTHENTIC_INTENSITY, 0.7f, 1.0f);
+        expect(fast.isFullyWet());
+
+        auto l1 = emu::testsignals::whiteNoise(31, 4096), r1 = emu::testsignals::whiteNoise(32, 4096);
+        auto l2 = l1, r2 = r1;
+        for (int start = 0; start < 4096; start += BlockSize)
+        {
+            regular.updateCoeffsBlock(BlockSize);
+            fast.updateCoeffsBlock(BlockSize);
+            regular.process(l1.data() + start, r1.data() + start, BlockSize);
+            fast.processWetOnly(l2.data() + start, r2.data() + start, BlockSize);
+        }
+        expectEquals(maxAbsDiff(l1, l2), 0.0f);
+        expectEquals(maxAbsDiff(r1, r2), 0.0f);
+
+        // Still ramping toward 100%: not yet safe to switch
+        fast.setMix(0.5f);
+        expect(! fast.isFullyWet());
+    }
+
+    // synth-255~2
+    void frequencyResponse()
+    {
+        beginTest("Frequency response sweep is log-spaced from 20 Hz to just below Nyquist");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        const float fs = static_cast<float>(SampleRate);
+        const auto curve = f.frequencyResponse(fs, 256);
+
+        expectEquals(curve.size(), size_t{ 256 });
+        expectWithinAbsoluteError(curve.front().first, 20.0f, 1.0e-3f);
+        expectLessThan(curve.back().first, 0.5f * fs);
+        expectGreaterThan(curve.back().first, 0.49f * fs);
+
+        const float ratio = curve[1].first / curve[0].first;
+        for (size_t k = 1; k < curve.size(); ++k)
+        {
+            expectWithinAbsoluteError(curve[k].first / curve[k - 1].first, ratio, 1.0e-3f);
+            expect(std::isfinite(curve[k].second));
+            expectWithinAbsoluteError(curve[k].second, f.cascadeL.magnitudeDbAt(curve[k].first, fs), 1.0e-4f);
+        }
+
+        expect(f.frequencyResponse(fs, 0).empty());
+        expectEquals(f.frequencyResponse(fs, 1).front().first, 20.0f);
+    }
+
+    // synth-256
+    void zeroModes()
+    {
+        beginTest("Zero modes: lowpass passes DC, highpass passes Nyquist, notch nulls the pole frequency");
+
+        auto filterWith = [](emu::ZeroMode mode)
+        {
+            emu::ZPlaneFilter f;
+            f.setZeroMode(mode);
+            prepareVowel(f);
+            return f;
+        };
+
+        const auto lp = filterWith(emu::ZeroMode::Lowpass);
+        const auto hp = filterWith(emu::ZeroMode::Highpass);
+        const auto notch = filterWith(emu::ZeroMode::Notch);
+        const auto bp = filterWith(emu::ZeroMode::Bandpass);
+
+        // Non-bandpass sections are unity in their passband, zero at the opposite end
+        for (const auto& s : lp.cascadeL.sections)
+        {
+            expectWithinAbsoluteError(s.dcGain(), 1.0f, 1.0e-3f);
+            expectLessThan(s.nyquistGain(), 1.0e-4f);
+        }
+        for (const auto& s : hp.cascadeL.sections)
+        {
+            expectWithinAbsoluteError(s.nyquistGain(), 1.0f, 1.0e-3f);
+            expectLessThan(s.dcGain(), 1.0e-3f);
+        }
+        expectGreaterThan(lp.dcGain(), 1000.0f * hp.dcGain());
+
+        const auto& poles = notch.getLastPoles();
+        for (size_t i = 0; i < poles.size(); ++i)
+            expectLessThan(notch.cascadeL.sections[i].magnitude(poles[i].theta), 1.0e-2f);
+
+        // Bandpass keeps the authentic zeros at 0.9 r
+        const auto& s = bp.cascadeL.sections[0];
+        expectWithinAbsoluteError(std::sqrt(s.b2 / s.b0), 0.9f * bp.getLastPoles()[0].r, 1.0e-4f);
+    }
+
+    // synth-257
+    void hpPosition()
+    {
+        beginTest("Post-drive rumble high-pass removes the DC that drive generates, pre-drive does not");
+
+        // DC-offset, asymmetric input: the pre-drive high-pass strips the offset, but tanh of the
+        // lopsided waveform still rectifies a DC component that only a post-drive high-pass removes
+        std::vector<float> input(48000);
+        for (size_t n = 0; n < input.size(); ++n)
+        {
+            const double w = juce::MathConstants<double>::twoPi * 500.0 * static_cast<double>(n) / SampleRate;
+            input[n] = static_cast<float>(0.3 + 0.5 * std::sin(w) + 0.4 * std::sin(2.0 * w + 0.5));
+        }
+
+        auto outputDc = [&](emu::HpPosition position)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f, 1.0f);
+            f.setDcBlock(false);
+            f.setRumbleHighpass(30.0f);
+            f.setHpPosition(position);
+            f.setPipeline(emu::PipelineConfig{ emu::PipelineConfig::All & ~emu::PipelineConfig::Cascade });
+            auto l = input, r = input;
+            processBlocks(f, l, r);
+
+            double mean = 0.0;
+            for (size_t n = l.size() / 2; n < l.size(); ++n) mean += l[n];
+            return std::abs(mean / static_cast<double>(l.size() / 2));
+        };
+
+        const double pre = outputDc(emu::HpPosition::PreDrive), post = outputDc(emu::HpPosition::PostDrive);
+        expectGreaterThan(pre, 0.01);
+        expectLessThan(post, 0.1 * pre);
+    }
+
+    // synth-257~2
+    void liveShapeSwap()
+    {
+        beginTest("Swapping shapes live changes the response at the same morph and keeps cascade state");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f);
+        auto l = emu::testsignals::whiteNoise(41, BlockSize), r = l;
+        f.process(l.data(), r.data(), BlockSize);
+
+        const auto vowelPoles = f.getLastPoles();
+        const float vowelMag = f.cascadeL.magnitudeAt(1000.0f, static_cast<float>(SampleRate));
+        std::array<std::pair<float, float>, emu::ZPlaneFilter::NumSections> state{};
+        for (size_t i = 0; i < state.size(); ++i)
+            state[i] = f.cascadeL.sections[i].state();
+
+        f.setShapePair(emu::BELL_A, emu::BELL_B, "Bell");
+        for (size_t i = 0; i < state.size(); ++i)
+            expect(f.cascadeL.sections[i].state() == state[i], "shape swap touched the cascade state");
+
+        f.updateCoeffsBlock(BlockSize);
+        bool moved = false;
+        for (size_t i = 0; i < vowelPoles.size(); ++i)
+            moved = moved || f.getLastPoles()[i].theta != vowelPoles[i].theta;
+        expect(moved, "poles unchanged after the swap");
+        expectNotEquals(f.cascadeL.magnitudeAt(1000.0f, static_cast<float>(SampleRate)), vowelMag);
+        for (size_t i = 0; i < state.size(); ++i)
+            expect(f.cascadeL.sections[i].state() == state[i], "coefficient update touched the cascade state");
+    }
+
+    // synth-258
+    void linearImpulseResponse()
+    {
+        beginTest("Linear impulse response convolved with a sine matches the linear filter's output");
+
+        using PC = emu::PipelineConfig;
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f, 1.0f);
+        const auto ir = f.linearImpulseResponse(8192, static_cast<float>(SampleRate));
+
+        // The IR ignores the cascade state left behind by earlier audio
+        auto noiseL = emu::testsignals::whiteNoise(51, 2048), noiseR = noiseL;
+        f.process(noiseL.data(), noiseR.data(), 2048);
+        expectEquals(maxAbsDiff(f.linearImpulseResponse(8192, static_cast<float>(SampleRate)), ir), 0.0f);
+
+        // Reference: the filter itself with only the linear cascade enabled (drive and saturation off)
+        const auto tone = emu::testsignals::sine(700.0f, 16384, static_cast<float>(SampleRate));
+        emu::ZPlaneFilter linear;
+        prepareVowel(linear, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f, 1.0f);
+        linear.setPipeline(PC{ PC::All & ~(PC::Drive | PC::Saturation | PC::InputHighPass | PC::OutputGain) });
+        auto l = tone, r = tone;
+        processBlocks(linear, l, r);
+
+        float worst = 0.0f, peak = 0.0f;
+        for (size_t n = ir.size(); n < tone.size(); n += 7)
+        {
+            double y = 0.0;
+            for (size_t k = 0; k < ir.size(); ++k)
+                y += static_cast<double>(ir[k]) * tone[n - k];
+            worst = std::max(worst, std::abs(static_cast<float>(y) - l[n]));
+            peak = std::max(peak, std::abs(l[n]));
+        }
+        expectGreaterThan(peak, 0.0f);
+        expectLessThan(worst, 1.0e-3f * peak + 1.0e-5f);
+    }
+
+    // synth-259
+    void limiterTiming()
+    {
+        beginTest("Limiter attack catches a transient and the gain recovers over the release");
+
+        // 0.2 sine (below the 0.5 knee) with a 20 ms burst at 4x full scale; mix 0 puts the dry input on the bus
+        constexpr int burstEnd = 960;
+        auto input = emu::testsignals::sine(300.0f, 48000, static_cast<float>(SampleRate));
+        for (int n = 0; n < static_cast<int>(input.size()); ++n)
+            input[static_cast<size_t>(n)] *= n < burstEnd ? 4.0f : 0.2f;
+
+        auto run = [&](float clip, float attackMs, float releaseMs)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, 0.0f);
+            f.setMixbusClip(clip);
+            f.setLimiterTiming(attackMs, releaseMs);
+            auto l = input, r = input;
+            processBlocks(f, l, r);
+            return l;
+        };
+
+        const auto reference = run(0.0f, 0.0f, 0.0f);
+        const auto instant = run(1.0f, 0.0f, 0.0f);
+        const auto timed = run(1.0f, 1.0f, 100.0f);
+
+        // Second half of the burst: instant clipping sits near full scale, the enveloped limiter holds the knee
+        auto peakIn = [](const std::vector<float>& y, int from, int to)
+        {
+            float p = 0.0f;
+            for (int n = from; n < to; ++n) p = std::max(p, std::abs(y[static_cast<size_t>(n)]));
+            return p;
+        };
+        expectGreaterThan(peakIn(instant, burstEnd / 2, burstEnd), 0.9f);
+        expectLessThan(peakIn(timed, burstEnd / 2, burstEnd), 0.6f);
+
+        // After the burst the quiet tone comes back up: still reduced shortly after, ~unity after 5 releases
+        auto gainAround = [&](int at)
+        {
+            return peakIn(timed, at, at + 160) / peakIn(reference, at, at + 160); // one 300 Hz cycle
+        };
+        expectLessThan(gainAround(burstEnd + 480), 0.5f);
+        expectGreaterThan(gainAround(burstEnd + 24000), 0.99f);
+        expectGreaterThan(gainAround(burstEnd + 4800), gainAround(burstEnd + 480));
+    }
+
+    // synth-260
+    void sectionParams()
+    {
+        beginTest("Section cutoff/Q round-trips through poleFromFreqQ");
+
+        for (const auto& pair : emu::SHAPE_PAIRS)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.3f, 0.2f);
+            f.setShapePair(*pair.a, *pair.b, pair.name);
+            f.updateCoeffsBlock(BlockSize);
+
+            const float fs = static_cast<float>(SampleRate);
+            for (size_t i = 0; i < static_cast<size_t>(emu::ZPlaneFilter::NumSections); ++i)
+            {
+                const auto& pole = f.getLastPoles()[i];
+                const auto [cutoff, q] = f.sectionParams(i, fs);
+                expect(cutoff >= 0.0f && cutoff <= 1.0f);
+
+                const auto back = emu::poleFromFreqQ(cutoff * 0.5f * fs, q, fs);
+                expectWithinAbsoluteError(back.theta, pole.theta, 1.0e-4f);
+                expectWithinAbsoluteError(back.r, std::min(pole.r, emu::MAX_POLE_RADIUS), 1.0e-4f);
+            }
+
+            // The same resonance expressed against a doubled rate sits at half the normalized cutoff
+            expectWithinAbsoluteError(f.sectionParams(0, 2.0f * fs).first, 0.5f * f.sectionParams(0, fs).first, 1.0e-6f);
+        }
+    }
+
+    // synth-260~2
+    void linearPhase()
+    {
+        beginTest("Linear-phase mode delays dry by the reported latency and makes the wet response symmetric");
+
+        using PC = emu::PipelineConfig;
+        auto run = [](float mix)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, emu::AUTHENTIC_DRIVE, mix);
+            f.setPipeline(PC{ PC::All & ~(PC::Drive | PC::Saturation | PC::InputHighPass | PC::OutputGain) });
+            f.setLinearPhase(true);
+            std::vector<float> l(32768, 0.0f);
+            l[1000] = 1.0f;
+            auto r = l;
+            processBlocks(f, l, r);
+            return std::make_pair(f.latencySamples(), l);
+        };
+
+        const auto [latency, dry] = run(0.0f);
+        expectEquals(latency, 8192);
+        for (size_t n = 0; n < dry.size(); ++n)
+            expectEquals(dry[n], n == static_cast<size_t>(1000 + latency) ? 1.0f : 0.0f);
+
+        // Zero phase: the wet impulse response is even around the delayed impulse
+        const auto wet = run(1.0f).second;
+        const size_t centre = static_cast<size_t>(1000 + latency);
+        float peak = 0.0f, asymmetry = 0.0f;
+        for (size_t k = 0; k < 2000; ++k)
+        {
+            peak = std::max(peak, std::abs(wet[centre + k]));
+            asymmetry = std::max(asymmetry, std::abs(wet[centre + k] - wet[centre - k]));
+        }
+        expectGreaterThan(peak, 0.0f);
+        expectLessThan(asymmetry, 1.0e-3f * peak);
+    }
+
+    // synth-261
+    void identicalShapes()
+    {
+        beginTest("Identical A/B shapes skip interpolation and give the same coefficients at any morph");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.0f, 0.4f);
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_A, "Vowel A");
+        expect(f.shapesIdentical);
+
+        std::array<emu::PolePair, 6> base{};
+        f.computeBasePoles(0.3f, base);
+        for (size_t i = 0; i < base.size(); ++i)
+        {
+            expectEquals(base[i].r, f.polesA[i].r);
+            expectEquals(base[i].theta, f.polesA[i].theta);
+        }
+
+        auto coeffsAt = [&f](float morph)
+        {
+            f.morphSmooth.setCurrentAndTargetValue(morph);
+            f.snapMorphPole();
+            f.updateCoeffsBlock(BlockSize);
+            std::array<float, 6 * 5> c{};
+            for (size_t i = 0; i < 6; ++i)
+            {
+                const auto& s = f.cascadeL.sections[i];
+                c[i * 5 + 0] = s.b0; c[i * 5 + 1] = s.b1; c[i * 5 + 2] = s.b2;
+                c[i * 5 + 3] = s.a1; c[i * 5 + 4] = s.a2;
+            }
+            return c;
+        };
+        const auto reference = coeffsAt(0.0f);
+        for (float morph : { 0.3f, 0.5f, 1.0f })
+            expect(coeffsAt(morph) == reference, "identical shapes changed with morph " + juce::String(morph));
+
+        // A regular pair turns the interpolation back on
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_B, "Vowel");
+        expect(! f.shapesIdentical);
+        expect(coeffsAt(1.0f) != reference);
+    }
+
+    // synth-262~2
+    void progressiveReveal()
+    {
+        beginTest("Progressive reveal leaves fewer resonant sections at morph 0 than at morph 1");
+
+        auto activeSections = [this](float morph, bool reveal)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, morph, 0.4f);
+            f.setProgressiveReveal(reveal);
+            f.updateCoeffsBlock(BlockSize);
+            int active = 0;
+            for (const auto& s : f.cascadeL.sections)
+            {
+                if (s.a2 > 1.0e-6f)
+                    ++active;
+                else // fully hidden: the radius went to 0, so the section is identity
+                    expect(s.b0 == 1.0f && s.b1 == 0.0f && s.b2 == 0.0f, "hidden section is not identity");
+            }
+            return active;
+        };
+
+        expectEquals(activeSections(0.0f, true), 2);
+        expectEquals(activeSections(0.5f, true), 4);
+        expectEquals(activeSections(1.0f, true), 6);
+        expectEquals(activeSections(0.0f, false), 6);
+    }
+
+    // synth-263
+    void sectionSaturation()
+    {
+        beginTest("Section saturation compresses a loud impulse more than no saturation");
+
+        auto impulsePeak = [](float saturation, float level)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, 0.4f, 0.0f);
+            f.setSectionSaturation(saturation);
+            std::vector<float> left(4096, 0.0f), right(4096, 0.0f);
+            left[0] = right[0] = level;
+            processBlocks(f, left, right);
+            float peak = 0.0f;
+            for (float x : left) peak = std::max(peak, std::abs(x));
+            return peak;
+        };
+
+        // Loud-impulse gain relative to the small-signal gain (the pre-drive tanh already compresses a little)
+        auto compression = [&](float saturation)
+        {
+            return (impulsePeak(saturation, 4.0f) / 4.0f) / (impulsePeak(saturation, 1.0e-3f) / 1.0e-3f);
+        };
+
+        const float clean = compression(0.0f), authentic = compression(0.2f), heavy = compression(1.0f);
+        expectLessThan(authentic, clean);
+        expectLessThan(heavy, authentic);
+    }
+
+    // synth-263~2
+    void oversamplingBypass()
+    {
+        beginTest("Oversampling is skipped with no drive or saturation, output only shifts by the latency");
+
+        emu::ZPlaneFilter os, base;
+        for (auto* f : { &os, &base })
+        {
+            prepareVowel(*f, SampleRate, 0.5f, 0.4f, 0.0f);
+            f->setSectionSaturation(0.0f);
+        }
+        os.setOversampling(2);
+        const int latency = os.latencySamples();
+        expectGreaterThan(latency, 0);
+
+        auto osL = emu::testsignals::whiteNoise(11, 4096);
+        for (auto& x : osL) x *= 0.25f;
+        auto osR = osL, baseL = osL, baseR = osL;
+        processBlocks(os, osL, osR);
+        processBlocks(base, baseL, baseR);
+
+        expectEquals(os.oversampledBlocks, 0);
+        float diff = 0.0f;
+        for (size_t n = 0; n + static_cast<size_t>(latency) < osL.size(); ++n)
+            diff = std::max(diff, std::abs(osL[n + static_cast<size_t>(latency)] - baseL[n]));
+        expectLessThan(diff, 1.0e-6f);
+
+        // Any drive above the threshold brings the oversampled path back
+        os.setDrive(0.5f);
+        processBlocks(os, osL, osR);
+        expectGreaterThan(os.oversampledBlocks, 0);
+    }
+
+    // synth-264
+    void dcBlocker()
+    {
+        beginTest("DC blocker (off by default) drives the mean of a DC-offset input toward zero on SUB shapes");
+
+        auto tailMean = [](std::optional<bool> dcBlock)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, 0.9f);
+            f.setShapePair(emu::SUB_A, emu::SUB_B, "Sub");
+            if (dcBlock) f.setDcBlock(*dcBlock);
+            auto left = emu::testsignals::sine(110.0f, 48000, static_cast<float>(SampleRate));
+            for (auto& x : left) x = 0.1f * x + 0.2f;
+            auto right = left;
+            processBlocks(f, left, right);
+
+            // Mean over the last 100 ms (11 whole periods of the 110 Hz tone)
+            double sum = 0.0;
+            for (size_t n = left.size() - 4800; n < left.size(); ++n) sum += left[n];
+            return static_cast<float>(sum / 4800.0);
+        };
+
+        const float blocked = tailMean(true), open = tailMean(false);
+        expectLessThan(std::abs(blocked), 0.01f);
+        expectGreaterThan(std::abs(open), 10.0f * std::abs(blocked), "DC passes the SUB shapes without the blocker");
+        expectEquals(tailMean(std::nullopt), open, "the blocker is off by default");
+    }
+
+    // synth-265
+    void intensityFastPath()
+    {
+        beginTest("Intensity-only updates reuse the cached base poles and match the full path");
+
+        auto sameCoeffs = [](const emu::BiquadSection& a, const emu::BiquadSection& b)
+        {
+            return a.b0 == b.b0 && a.b1 == b.b1 && a.b2 == b.b2 && a.a1 == b.a1 && a.a2 == b.a2;
+        };
+
+        for (float spread : { 0.0f, 0.5f })
+        {
+            emu::ZPlaneFilter fast, full;
+            for (auto* f : { &fast, &full })
+            {
+                prepareVowel(*f, SampleRate, 0.3f, 0.2f);
+                f->setStereoSpread(spread);
+                f->updateCoeffsBlock(BlockSize);
+            }
+            full.setIntensityFastPath(false);
+            const int before = fast.intensityOnlyUpdates;
+
+            for (float intensity : { 0.5f, 0.9f, 0.0f })
+            {
+                for (auto* f : { &fast, &full })
+                {
+                    f->setIntensity(intensity);
+                    f->intensitySmooth.setCurrentAndTargetValue(intensity);
+                    f->updateCoeffsBlock(BlockSize);
+                }
+
+                for (size_t i = 0; i < 6; ++i)
+                {
+                    expect(sameCoeffs(fast.cascadeL.sections[i], full.cascadeL.sections[i]), "left section " + juce::String(static_cast<int>(i)));
+                    expect(sameCoeffs(fast.cascadeR.sections[i], full.cascadeR.sections[i]), "right section " + juce::String(static_cast<int>(i)));
+                }
+            }
+
+            expectEquals(fast.intensityOnlyUpdates - before, 3);
+            expectEquals(full.intensityOnlyUpdates, 0);
+
+            // A morph move goes back through interpolation
+            fast.morphSmooth.setCurrentAndTargetValue(0.6f);
+            fast.updateCoeffsBlock(BlockSize);
+            expectEquals(fast.intensityOnlyUpdates - before, 3);
+        }
+    }
+
+    // synth-265~2
+    void oversamplingAliasing()
+    {
+        beginTest("2x oversampling cuts the aliased drive harmonics above Nyquist/2");
+
+        // A 9 kHz tone has no real harmonics below Nyquist: the odd harmonics fold back to 21 kHz (3rd),
+        // 3 kHz (5th) and 15 kHz (7th), so everything measured between 12 and 24 kHz is aliasing
+        constexpr float toneHz = 9000.0f;
+        auto aliasRatio = [](int factor)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, emu::AUTHENTIC_INTENSITY, 1.0f);
+            f.setOversampling(factor);
+            auto l = emu::testsignals::sine(toneHz, 14400, static_cast<float>(SampleRate));
+            for (auto& x : l) x *= 0.8f;
+            auto r = l;
+            processBlocks(f, l, r);
+
+            // 4800-sample window: 10 Hz bins, the tone and every alias sit on a bin
+            auto energyAt = [&l](double hz)
+            {
+                const double w = juce::MathConstants<double>::twoPi * hz / SampleRate;
+                std::complex<double> sum;
+                for (size_t n = l.size() - 4800; n < l.size(); ++n)
+                    sum += static_cast<double>(l[n]) * std::polar(1.0, -w * static_cast<double>(n));
+                return std::norm(sum);
+            };
+            double aliased = 0.0;
+            for (double hz = 12000.0; hz < 24000.0; hz += 10.0)
+                aliased += energyAt(hz);
+            return aliased / energyAt(toneHz);
+        };
+
+        const double base = aliasRatio(1), twice = aliasRatio(2);
+        logMessage("Alias/fundamental energy above 12 kHz: 1x " + juce::String(base) + ", 2x " + juce::String(twice));
+        expectGreaterThan(base, 1.0e-6, "full drive should alias at the base rate");
+        expectLessThan(twice, 0.25 * base, "2x oversampling did not reduce aliasing");
+    }
+
+    // synth-266
+    void morphOnePole()
+    {
+        beginTest("One-pole morph/intensity smoothing reaches a step target with the set time constant");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.0f, 0.2f);
+        f.morphSmooth.reset(SampleRate, 0.0);     // isolate the one-pole stage from the linear ramps
+        f.intensitySmooth.reset(SampleRate, 0.0);
+        f.setMorphSmoothingMs(20.0f);             // 960 samples = two 480-sample blocks
+
+        f.updateCoeffsBlock(1.0f, 0.8f, 480);
+        f.updateCoeffsBlock(1.0f, 0.8f, 480);
+        const float oneTau = 1.0f - std::exp(-1.0f);
+        expectWithinAbsoluteError(f.coeffMorph(), oneTau, 1.0e-4f);
+        expectWithinAbsoluteError(f.coeffIntensity(), 0.2f + 0.6f * oneTau, 1.0e-4f);
+        expectWithinAbsoluteError(f.lastMorph, f.coeffMorph(), 1.0e-7f); // coefficients follow the pole
+
+        // Five time constants: within 1%, then it settles exactly on the target
+        for (int b = 0; b < 8; ++b)
+            f.updateCoeffsBlock(1.0f, 0.8f, 480);
+        expectWithinAbsoluteError(f.coeffMorph(), 1.0f, 0.01f);
+        for (int b = 0; b < 40; ++b)
+            f.updateCoeffsBlock(1.0f, 0.8f, 480);
+        expectEquals(f.coeffMorph(), 1.0f);
+        expectEquals(f.coeffIntensity(), 0.8f);
+
+        // Off: the coefficients jump straight to the (unramped) target
+        f.setMorphSmoothingMs(0.0f);
+        f.updateCoeffsBlock(0.25f, 0.8f, 480);
+        expectEquals(f.coeffMorph(), 0.25f);
+    }
+
+    // synth-266~2
+    void normalizedResponse()
+    {
+        beginTest("Peak-normalized response tops out at exactly 0 dB and keeps the shape");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.4f, 0.7f);
+
+        std::array<float, 64> freqs{}, norm{};
+        for (size_t k = 0; k < freqs.size(); ++k)
+            freqs[k] = 20.0f * std::pow(1000.0f, static_cast<float>(k) / static_cast<float>(freqs.size() - 1)); // 20 Hz .. 20 kHz
+        f.magnitudeResponseNormalized(static_cast<float>(SampleRate), freqs.data(), norm.data(), freqs.size());
+
+        float rawPeak = 0.0f;
+        for (float hz : freqs)
+            rawPeak = std::max(rawPeak, f.cascadeL.magnitudeAt(hz, static_cast<float>(SampleRate)));
+        expect(std::abs(rawPeak - 1.0f) > 1.0e-3f, "raw response already peaks at 0 dB");
+
+        expectEquals(*std::max_element(norm.begin(), norm.end()), 1.0f);
+        for (size_t k = 0; k < freqs.size(); ++k)
+            expectWithinAbsoluteError(norm[k] * rawPeak, f.cascadeL.magnitudeAt(freqs[k], static_cast<float>(SampleRate)),
+                                      1.0e-5f * rawPeak);
+    }
+
+    // synth-267
+    void bipolarShapes()
+    {
+        beginTest("Bipolar character: 50% is the center shape, the ends are the left and right shapes");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.4f);
+        f.setBipolarShapes(emu::LOW_A, emu::VOWEL_A, emu::BELL_B);
+
+        auto expectPoles = [this, &f](float morph, const emu::Shape& shape)
+        {
+            std::array<emu::PolePair, 6> base{};
+            f.computeBasePoles(morph, base);
+            for (size_t i = 0; i < base.size(); ++i)
+            {
+                expectWithinAbsoluteError(base[i].r, shape[2 * i], 1.0e-6f);
+                expectWithinAbsoluteError(base[i].theta, shape[2 * i + 1], 1.0e-6f);
+            }
+        };
+        expectPoles(0.5f, emu::VOWEL_A);
+        expectPoles(0.0f, emu::LOW_A);
+        expectPoles(1.0f, emu::BELL_B);
+
+        // The coefficients at 50% are those of the center shape on its own
+        emu::ZPlaneFilter center;
+        prepareVowel(center, SampleRate, 0.5f, 0.4f);
+        center.setShapePair(emu::VOWEL_A, emu::VOWEL_A, "Center");
+        f.updateCoeffsBlock(BlockSize);
+        center.updateCoeffsBlock(BlockSize);
+        for (size_t i = 0; i < 6; ++i)
+        {
+            expectWithinAbsoluteError(f.cascadeL.sections[i].a1, center.cascadeL.sections[i].a1, 1.0e-6f);
+            expectWithinAbsoluteError(f.cascadeL.sections[i].a2, center.cascadeL.sections[i].a2, 1.0e-6f);
+        }
+
+        // Back to a regular pair: morph 0.5 is halfway between A and B again
+        f.setShapePair(emu::VOWEL_A, emu::VOWEL_B, "Vowel");
+        std::array<emu::PolePair, 6> base{};
+        f.computeBasePoles(0.5f, base);
+        expect(std::abs(base[0].theta - emu::VOWEL_A[1]) > 1.0e-4f);
+    }
+
+    // synth-267~2
+    void audioRateMorph()
+    {
+        beginTest("Audio-rate morph of a square wave stays finite and bounded");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.8f);
+
+        constexpr int num = 9600;
+        std::vector<float> left(num), right(num), morph(num), intensity(num);
+        for (int n = 0; n < num; ++n)
+        {
+            left[static_cast<size_t>(n)] = right[static_cast<size_t>(n)] = ((n / 120) % 2 == 0) ? 0.5f : -0.5f; // 200 Hz square
+            const double t = static_cast<double>(n) / SampleRate;
+            morph[static_cast<size_t>(n)] = static_cast<float>(0.5 + 0.5 * std::sin(juce::MathConstants<double>::twoPi * 400.0 * t));
+            intensity[static_cast<size_t>(n)] = static_cast<float>(0.8 + 0.2 * std::sin(juce::MathConstants<double>::twoPi * 130.0 * t));
+        }
+
+        for (int start = 0; start < num; start += BlockSize)
+        {
+            const int len = std::min(BlockSize, num - start);
+            f.processStereoModulatedPerSample(left.data() + start, right.data() + start, len,
+                                              morph.data() + start, intensity.data() + start, emu::AUTHENTIC_DRIVE, 1.0f);
+        }
+
+        float peak = 0.0f;
+        bool finite = true;
+        for (int n = 0; n < num; ++n)
+        {
+            finite = finite && std::isfinite(left[static_cast<size_t>(n)]) && std::isfinite(right[static_cast<size_t>(n)]);
+            peak = std::max({ peak, std::abs(left[static_cast<size_t>(n)]), std::abs(right[static_cast<size_t>(n)]) });
+        }
+        expect(finite);
+        expectGreaterThan(peak, 1.0e-3f);
+        expectLessThan(peak, 1.0f);
+
+        // The coefficients track the last morph sample
+        expectEquals(f.lastMorph, morph.back());
+    }
+
+    // synth-268
+    void instanceSeed()
+    {
+        beginTest("Default instances draw decorrelated noise, equal seeds draw identical noise");
+
+        emu::ZPlaneFilter a, b;
+        expect(a.getSeed() != b.getSeed());
+
+        double ab = 0.0, aa = 0.0, bb = 0.0;
+        for (int n = 0; n < 48000; ++n)
+        {
+            const double x = a.nextNoise(), y = b.nextNoise();
+            ab += x * y; aa += x * x; bb += y * y;
+        }
+        expectLessThan(std::abs(ab / std::sqrt(aa * bb)), 0.02);
+
+        emu::ZPlaneFilter c, d;
+        c.setSeed(1234);
+        d.setSeed(1234);
+        expect(c.getSeed() == 1234u);
+        bool identical = true, inRange = true;
+        for (int n = 0; n < 4096; ++n)
+        {
+            const float x = c.nextNoise(), y = d.nextNoise();
+            identical = identical && x == y;
+            inRange = inRange && x >= -1.0f && x < 1.0f;
+        }
+        expect(identical);
+        expect(inRange);
+    }
+
+    // synth-269
+    void morphGainComp()
+    {
+        beginTest("Morph gain compensation keeps noise RMS level across the morph sweep");
+
+        const auto noise = emu::testsignals::whiteNoise(5, 24000);
+        auto rmsSpreadDb = [&noise](bool comp)
+        {
+            float lo = 1.0e9f, hi = 0.0f;
+            for (int step = 0; step <= 10; ++step)
+            {
+                emu::ZPlaneFilter f;
+                prepareVowel(f, SampleRate, 0.1f * static_cast<float>(step), 0.4f, 0.0f);
+                f.setSectionSaturation(0.0f);
+                f.setMorphGainComp(comp);
+                auto left = noise;
+                for (auto& x : left) x *= 0.05f;
+                auto right = left;
+                processBlocks(f, left, right);
+
+                double sum = 0.0;
+                for (size_t n = 12000; n < left.size(); ++n) sum += static_cast<double>(left[n]) * left[n];
+                const float rms = static_cast<float>(std::sqrt(sum / 12000.0));
+                lo = std::min(lo, rms);
+                hi = std::max(hi, rms);
+            }
+            return juce::Decibels::gainToDecibels(hi / lo);
+        };
+
+        const float off = rmsSpreadDb(false), on = rmsSpreadDb(true);
+        logMessage("RMS spread across the sweep: off " + juce::String(off) + " dB, on " + juce::String(on) + " dB");
+        expectGreaterThan(off, 2.0f, "the Vowel sweep should change level without compensation");
+        expectLessThan(on, 1.0f);
+    }
+
+    // synth-269~2
+    void midSideMode()
+    {
+        beginTest("MidSide mode: a mono input has no side energy and ignores the side cascade");
+
+        const auto mono = emu::testsignals::pinkNoise(3, 8192);
+        auto render = [&mono](emu::MsMode mode, float rightOffset)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.3f, 0.6f, emu::AUTHENTIC_DRIVE, 0.7f);
+            f.setMsMode(mode);
+            f.setRightMorphOffset(rightOffset);
+            auto left = mono;
+            for (auto& x : left) x *= 0.25f;
+            auto right = left;
+            processBlocks(f, left, right);
+            return std::make_pair(left, right);
+        };
+
+        const auto stereo = render(emu::MsMode::Stereo, 0.0f);
+        const auto ms = render(emu::MsMode::MidSide, 0.0f);
+        const auto shifted = render(emu::MsMode::MidSide, 0.5f); // different side filtering
+
+        expectEquals(maxAbsDiff(ms.first, ms.second), 0.0f, "mono input produced side energy");
+        expectLessThan(maxAbsDiff(ms.first, stereo.first), 1.0e-6f);
+        expectEquals(maxAbsDiff(shifted.first, ms.first), 0.0f);
+        expectEquals(maxAbsDiff(shifted.second, ms.second), 0.0f);
+    }
+
+    // synth-270
+    void stereoMorph()
+    {
+        beginTest("Per-channel morph interpolates L/R separately and splits a mono input");
+
+        for (float spread : { 0.0f, 0.3f })
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.5f, 0.5f);
+            f.setStereoSpread(spread);
+            f.updateCoeffsStereo(0.2f, 0.8f, 0.5f);
+
+            std::array<emu::PolePair, 6> expectedL{}, expectedR{};
+            f.computePoles(0.2f, expectedL);
+            f.computePoles(0.8f, expectedR);
+            for (size_t i = 0; i < 6; ++i)
+            {
+                expectWithinAbsoluteError(f.getLastPoles()[i].theta, expectedL[i].theta, 1.0e-6f);
+                expectWithinAbsoluteError(f.getLastPolesRight()[i].theta, expectedR[i].theta, 1.0e-6f);
+                expectWithinAbsoluteError(f.getLastPolesRight()[i].r, expectedR[i].r, 1.0e-6f);
+            }
+
+            auto left = emu::testsignals::whiteNoise(9, 4096);
+            for (auto& x : left) x *= 0.25f;
+            auto right = left;
+            processBlocks(f, left, right);
+            expectGreaterThan(maxAbsDiff(left, right), 1.0e-3f, "mono input stayed mono");
+
+            // Later block updates keep the same L/R positions
+            expectWithinAbsoluteError(f.getLastPolesRight()[0].theta, expectedR[0].theta, 1.0e-6f);
+        }
+    }
+
+    // synth-277
+    void frequencyShift()
+    {
+        beginTest("+12 semitone tune roughly doubles each pole frequency");
+
+        for (double sampleRate : { 48000.0, 96000.0 })
+        {
+            emu::ZPlaneFilter plain, tuned;
+            prepareVowel(plain, sampleRate, 0.4f, 0.4f);
+            prepareVowel(tuned, sampleRate, 0.4f, 0.4f);
+            tuned.setFrequencyShift(12.0f);
+            tuned.updateCoeffsBlock(BlockSize);
+
+            auto hz = [sampleRate](const emu::PolePair& p) { return p.theta * sampleRate / juce::MathConstants<double>::twoPi; };
+            for (size_t i = 0; i < 6; ++i)
+            {
+                const double before = hz(plain.getLastPoles()[i]), after = hz(tuned.getLastPoles()[i]);
+                if (before > 0.2 * sampleRate) continue; // a doubled angle would pass Nyquist and clamp
+                expectWithinAbsoluteError(after / before, 2.0, 0.1);
+            }
+        }
+
+        // Down an octave and back to neutral
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.4f, 0.4f);
+        const auto neutral = f.getLastPoles();
+        f.setFrequencyShift(-12.0f);
+        f.updateCoeffsBlock(BlockSize);
+        expectWithinAbsoluteError(f.getLastPoles()[0].theta, 0.5f * neutral[0].theta, 1.0e-5f);
+        f.setFrequencyShift(0.0f);
+        f.updateCoeffsBlock(BlockSize);
+        expectEquals(f.getLastPoles()[0].theta, neutral[0].theta);
+    }
+
+    // synth-278
+    void formantStretch()
+    {
+        beginTest("Formant stretch spreads the poles around their mean angle");
+
+        auto spreadAndMean = [](float stretch)
+        {
+            emu::ZPlaneFilter f;
+            prepareVowel(f, SampleRate, 0.4f, 0.4f);
+            f.setFormantStretch(stretch);
+            f.updateCoeffsBlock(BlockSize);
+            float lo = 10.0f, hi = 0.0f, mean = 0.0f;
+            for (const auto& p : f.getLastPoles())
+            {
+                lo = std::min(lo, p.theta);
+                hi = std::max(hi, p.theta);
+                mean += p.theta / 6.0f;
+            }
+            return std::make_pair(hi - lo, mean);
+        };
+
+        const auto neutral = spreadAndMean(1.0f), wide = spreadAndMean(2.0f), narrow = spreadAndMean(0.5f);
+        expectGreaterThan(wide.first, 1.5f * neutral.first);
+        expectLessThan(narrow.first, 0.75f * neutral.first);
+
+        // Stretching pivots on the cluster mean (unless a pole clamps at 0 or pi)
+        expectWithinAbsoluteError(narrow.second, neutral.second, 1.0e-5f);
+
+        // Extreme stretch keeps every angle inside [0, pi]
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 1.0f, 0.4f);
+        f.setFormantStretch(4.0f);
+        f.updateCoeffsBlock(BlockSize);
+        for (const auto& p : f.getLastPoles())
+        {
+            expectGreaterOrEqual(p.theta, 0.0f);
+            expectLessOrEqual(p.theta, juce::MathConstants<float>::pi);
+        }
+    }
+
+    // synth-279
+    void flushToZero()
+    {
+        beginTest("Biquad state decays to exactly zero after an impulse");
+
+        for (float r : { 0.9f, emu::MAX_POLE_RADIUS, 0.999f })
+        {
+            emu::BiquadSection s;
+            float a1, a2, b0, b1, b2;
+            emu::poleToBiquad({ r, 0.05f }, a1, a2, b0, b1, b2);
+            s.setCoeffs(b0, b1, b2, a1, a2);
+            s.setSaturation(0.0f);
+
+            // The envelope falls as r^n: below the threshold after ln(1e15) / (1 - r) samples, doubled for margin
+            const int limit = static_cast<int>(2.0 * std::log(1.0e15) / (1.0 - r));
+            s.process(1.0f);
+            int settledAt = -1;
+            for (int n = 1; n < limit && settledAt < 0; ++n)
+            {
+                s.process(0.0f);
+                if (s.state() == std::make_pair(0.0f, 0.0f))
+                    settledAt = n;
+            }
+            expect(settledAt > 0, "state still not zero after " + juce::String(limit) + " samples at r = " + juce::String(r));
+
+            // Once flushed it stays at zero
+            for (int n = 0; n < 100; ++n)
+                expectEquals(s.process(0.0f), 0.0f);
+        }
+    }
+
+    // synth-281
+    void sectionCoeffsReference()
+    {
+        beginTest("Section 0 at morph 0 matches poleToBiquad of the remapped, boosted shape A pole");
+
+        constexpr double sampleRate = 96000.0; // bilinear remap active
+        constexpr float intensity = 0.5f;
+        emu::ZPlaneFilter f;
+        prepareVowel(f, sampleRate, 0.0f, intensity);
+
+        emu::PolePair p = emu::remapPole48kToFs({ emu::VOWEL_A[0], emu::VOWEL_A[1] }, sampleRate);
+        p.r = std::min(p.r * (1.0f + intensity * 0.06f), emu::MAX_POLE_RADIUS);
+        float a1, a2, b0, b1, b2;
+        emu::poleToBiquad(p, a1, a2, b0, b1, b2, 0.9f);
+
+        for (int channel : { 0, 1 })
+        {
+            const auto c = f.sectionCoeffs(channel, 0);
+            expectWithinAbsoluteError(c.b0, b0, 1.0e-6f);
+            expectWithinAbsoluteError(c.b1, b1, 1.0e-6f);
+            expectWithinAbsoluteError(c.b2, b2, 1.0e-6f);
+            expectWithinAbsoluteError(c.a1, a1, 1.0e-6f);
+            expectWithinAbsoluteError(c.a2, a2, 1.0e-6f);
+        }
+    }
+
+    // synth-282
+    void seedState()
+    {
+        beginTest("Seeded cascade state round-trips through the getters and reproduces the output");
+
+        std::array<std::pair<float, float>, 6> left{}, right{};
+        for (size_t i = 0; i < 6; ++i)
+        {
+            left[i]  = { 0.01f * static_cast<float>(i + 1), -0.02f * static_cast<float>(i) };
+            right[i] = { -0.03f * static_cast<float>(i), 0.005f * static_cast<float>(i + 2) };
+        }
+
+        emu::ZPlaneFilter a, b;
+        for (auto* f : { &a, &b })
+        {
+            prepareVowel(*f, SampleRate, 0.5f, 0.4f);
+            f->seedState(left, right);
+        }
+        for (size_t i = 0; i < 6; ++i)
+        {
+            expect(a.cascadeL.sections[i].state() == left[i]);
+            expect(a.cascadeR.sections[i].state() == right[i]);
+        }
+
+        // Same seed, same input: identical output; the seeded state rings out on silence
+        std::vector<float> aL(1024, 0.0f), aR(1024, 0.0f), bL(1024, 0.0f), bR(1024, 0.0f);
+        processBlocks(a, aL, aR);
+        processBlocks(b, bL, bR);
+        expectEquals(maxAbsDiff(aL, bL), 0.0f);
+        expectEquals(maxAbsDiff(aR, bR), 0.0f);
+        expectGreaterThan(maxAbsDiff(aL, std::vector<float>(aL.size(), 0.0f)), 0.0f);
+
+        a.reset();
+        for (const auto& s : a.cascadeL.sections)
+            expect(s.state() == std::make_pair(0.0f, 0.0f));
+    }
+
+    // synth-283
+    void nanEvents()
+    {
+        beginTest("Unstable coefficients raise the non-finite event count, normal input leaves it at 0");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.4f);
+        auto left = emu::testsignals::whiteNoise(3, 4096), right = left;
+        processBlocks(f, left, right);
+        expectEquals(f.nanEvents(), static_cast<uint64_t>(0));
+
+        // Poles well outside the unit circle blow up within a few hundred samples
+        f.setSectionSaturation(0.0f);
+        for (auto* c : { &f.cascadeL, &f.cascadeR })
+            c->sections[0].setCoeffs(1.0f, 0.0f, 0.0f, -2.5f, 1.5f);
+        left = emu::testsignals::whiteNoise(4, 4096);
+        right = left;
+        f.process(left.data(), right.data(), static_cast<int>(left.size()));
+
+        const uint64_t events = f.nanEvents();
+        expectGreaterThan(events, static_cast<uint64_t>(0), "no non-finite outputs counted");
+        for (size_t n = 0; n < left.size(); ++n)
+            expect(std::isfinite(left[n]) && std::isfinite(right[n]));
+
+        // The count only ever rises
+        f.process(left.data(), right.data(), static_cast<int>(left.size()));
+        expectGreaterOrEqual(f.nanEvents(), events);
+    }
+
+    // synth-284
+    void rampedMix()
+    {
+        beginTest("A 0 -> 1 mix ramp across one block blends monotonically from dry to wet");
+
+        constexpr int num = 8192, window = 512;
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.4f, 0.3f, 0.0f);
+        emu::ZPlaneFilter wetRef = f;
+
+        const auto dry = emu::testsignals::whiteNoise(5, num);
+        auto wetL = dry, wetR = dry;
+        wetRef.processStereoRamped(wetL.data(), wetR.data(), num, 0.3f, 0.3f, 1.0f, 1.0f);
+        auto left = dry, right = dry;
+        f.processStereoRamped(left.data(), right.data(), num, 0.3f, 0.3f, 0.0f, 1.0f);
+
+        // Starts (one ramp step in) essentially dry, lands exactly on wet
+        expectWithinAbsoluteError(left.front(), dry.front(), 1.0e-3f);
+        expectWithinAbsoluteError(left.back(), wetL.back(), 1.0e-6f);
+
+        // Least-squares fit of each window to a * wet + b * dry: the wet weight rises, the dry weight falls
+        float lastWet = -1.0f, lastDry = 2.0f;
+        for (int start = 0; start < num; start += window)
+        {
+            double ww = 0, dd = 0, wd = 0, yw = 0, yd = 0;
+            for (int n = start; n < start + window; ++n)
+            {
+                const double w = wetL[static_cast<size_t>(n)], d = dry[static_cast<size_t>(n)], y = left[static_cast<size_t>(n)];
+                ww += w * w; dd += d * d; wd += w * d; yw += y * w; yd += y * d;
+            }
+            const double det = ww * dd - wd * wd;
+            const float wetWeight = static_cast<float>((yw * dd - yd * wd) / det);
+            const float dryWeight = static_cast<float>((yd * ww - yw * wd) / det);
+            expectGreaterThan(wetWeight, lastWet);
+            expectLessThan(dryWeight, lastDry);
+            lastWet = wetWeight;
+            lastDry = dryWeight;
+        }
+
+        // The regular smoothing picks up from the ramp's end value
+        expectEquals(f.mixSmooth.getCurrentValue(), 1.0f);
+        expect(! f.mixSmooth.isSmoothing());
+    }
+
+    // synth-284
+    void rampedTargets()
+    {
+        beginTest("A ramped block matches per-sample targets, stepped before the first sample");
+
+        constexpr int num = 256;
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.4f, 0.1f, 0.2f);
+        emu::ZPlaneFilter ref = f;
+
+        // Sample n runs at start + (end - start) * (n + 1) / num: one step in at the first, on target at the last
+        const auto input = emu::testsignals::whiteNoise(9, num);
+        auto left = input, right = input;
+        f.processStereoRamped(left.data(), right.data(), num, 0.1f, 0.9f, 0.2f, 1.0f);
+        auto refL = input, refR = input;
+        for (int n = 0; n < num; ++n)
+        {
+            const float t = static_cast<float>(n + 1) / static_cast<float>(num);
+            ref.driveSmooth.setCurrentAndTargetValue(0.1f + 0.8f * t);
+            ref.mixSmooth.setCurrentAndTargetValue(0.2f + 0.8f * t);
+            ref.process(refL.data() + n, refR.data() + n, 1);
+        }
+        expectLessThan(maxAbsDiff(left, refL), 1.0e-4f);
+        expectLessThan(maxAbsDiff(right, refR), 1.0e-4f);
+
+        // Sample-count smoothing: the first grid point's coefficients already use the stepped morph
+        emu::ZPlaneFilter s;
+        prepareVowel(s, SampleRate, 0.2f, 0.4f);
+        s.setSmoothingSamples(64);
+        s.setMorph(0.8f);
+        float l = 0.0f, r = 0.0f;
+        s.processStereoRamped(&l, &r, 1, 0.2f, 0.2f, 1.0f, 1.0f);
+        expectWithinAbsoluteError(s.lastMorph, 0.2f + 0.6f / 64.0f, 1.0e-6f);
+    }
+
+    // synth-213
+    void resetCleanRestart()
+    {
+        beginTest("reset() clears alignment delays, linear-phase buffers, limiter and fade state");
+
+        auto configure = [](emu::ZPlaneFilter& f)
+        {
+            prepareVowel(f, SampleRate, 0.5f, 0.4f);
+            f.setStereoSpread(0.6f);
+            f.setSpreadAlign(true);
+            f.setLinearPhase(true);
+            f.setMixbusClip(0.5f);
+            f.setLimiterTiming(1.0f, 200.0f);
+        };
+
+        emu::ZPlaneFilter used, fresh;
+        configure(used);
+        configure(fresh);
+
+        auto loudL = emu::testsignals::whiteNoise(8, 16384), loudR = emu::testsignals::whiteNoise(9, 16384);
+        for (auto* v : { &loudL, &loudR })
+            for (auto& x : *v) x *= 4.0f;
+        processBlocks(used, loudL, loudR);
+        used.reconfigFade.setCurrentAndTargetValue(0.0f);
+        used.reconfigFade.setTargetValue(1.0f);
+
+        used.reset();
+        fresh.reset();
+        auto aL = emu::testsignals::whiteNoise(10, 8192), aR = aL;
+        auto bL = aL, bR = aR;
+        processBlocks(used, aL, aR);
+        processBlocks(fresh, bL, bR);
+        expectLessThan(maxAbsDiff(aL, bL), 1.0e-6f, "state left behind after reset");
+        expectLessThan(maxAbsDiff(aR, bR), 1.0e-6f, "state left behind after reset");
+    }
+
+    // synth-269
+    void morphGainTable()
+    {
+        beginTest("Morph gain compensation reads the precomputed table, rebuilt or swapped in on shape changes");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.25f, 0.5f); // a table grid point: the lookup equals a direct probe
+        f.setMorphGainComp(true);
+        auto expectProbeMatch = [this, &f]
+        {
+            f.computeCoeffs();
+            const float probe = f.makeupGain(f.lastInterpPoles, f.zeroFactorAt(0.25f));
+            expectWithinAbsoluteError(juce::Decibels::gainToDecibels(f.morphGainL.getTargetValue()),
+                                      juce::Decibels::gainToDecibels(probe), 0.01f);
+        };
+        expectProbeMatch();
+
+        const float vowelGain = f.morphGainL.getTargetValue();
+        f.setShapePair(emu::LOW_A, emu::BELL_B, "Other");
+        expectProbeMatch();
+        expectNotEquals(f.morphGainL.getTargetValue(), vowelGain);
+
+        // Built-in pairs swap in their precomputed tables, which match a probe after a raw-shape rebuild
+        f.setShapePair(emu::getPair(1));
+        expectProbeMatch();
+        const auto fromPair = f.makeupTableDb;
+        f.rebuildMakeupTable();
+        expect(f.makeupTableDb == fromPair, "the precomputed pair table differs from a rebuild");
+        f.setShapePair(emu::getPair(0));
+        f.computeCoeffs();
+        expectWithinAbsoluteError(f.morphGainL.getTargetValue(), vowelGain, 1.0e-6f);
+
+        // Between grid points the interpolated gain stays close to the probe
+        float worstDb = 0.0f;
+        for (float morph = 0.03f; morph < 1.0f; morph += 0.1f)
+        {
+            std::array<emu::PolePair, 6> poles;
+            f.computePoles(morph, poles);
+            worstDb = std::max(worstDb, std::abs(juce::Decibels::gainToDecibels(f.interpolatedMakeupGain(morph, f.lastIntensity))
+                                                 - juce::Decibels::gainToDecibels(f.makeupGain(poles, f.zeroFactorAt(morph)))));
+        }
+        expectLessThan(worstDb, 1.5f);
+    }
+
+    // synth-270
+    void stereoMorphTargets()
+    {
+        beginTest("Per-channel morph targets land exactly and survive right-offset updates until setMorph");
+
+        emu::ZPlaneFilter f;
+        prepareVowel(f, SampleRate, 0.5f, 0.5f);
+        f.setStereoSpread(0.3f);
+        f.updateCoeffsStereo(0.9f, 0.1f, 0.5f); // morphL + spread / 2 would clamp at 1
+
+        std::array<emu::PolePair, 6> expectedL{}, expectedR{};
+        f.computePoles(0.9f, expectedL);
+        f.computePoles(0.1f, expectedR);
+        auto expectTargets = [&]
+        {
+            for (size_t i = 0; i < 6; ++i)
+            {
+                expectWithinAbsoluteError(f.getLastPoles()[i].theta, expectedL[i].theta, 1.0e-6f);
+                expectWithinAbsoluteError(f.getLastPolesRight()[i].theta, expectedR[i].theta, 1.0e-6f);
+            }
+        };
+        expectTargets();
+
+        // The processor writes the right offset every block; it must not move pinned targets
+        f.setRightMorphOffset(0.25f);
+        f.updateCoeffsBlock(BlockSize);
+        f.computeCoeffs();
+        expectTargets();
+
+        // setMorph hands control back to the shared morph
+        f.setStereoSpread(0.0f);
+        f.setRightMorphOffset(0.0f);
+        f.setMorph(0.9f);
+        f.updateCoeffsBlock(BlockSize);
+        for (size_t i = 0; i < 6; ++i)
+            expectWithinAbsoluteError(f.getLastPolesRight()[i].theta, f.getLastPoles()[i].theta, 1.0e-6f);
+    }
+};
+
+static ZPlaneFilterTests zPlaneFilterTests;